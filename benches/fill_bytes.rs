@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::RngCore;
+use squirrel_rng::SquirrelRng;
+
+/// The previous `fill_bytes` strategy, which draws a `u64` per eight bytes.
+fn fill_bytes_via_u64(rng: &mut impl RngCore, dest: &mut [u8]) {
+    for chunk in dest.chunks_mut(8) {
+        let n = chunk.len();
+        if n > 4 {
+            chunk.copy_from_slice(&rng.next_u64().to_le_bytes()[..n]);
+        } else {
+            chunk.copy_from_slice(&rng.next_u32().to_le_bytes()[..n]);
+        }
+    }
+}
+
+fn fill_bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill_bytes");
+    let mut buf = vec![0u8; 4096];
+
+    group.bench_function("via_u32", |b| {
+        let mut rng = SquirrelRng::with_seed(3);
+        b.iter(|| {
+            rng.fill_bytes(&mut buf);
+            black_box(&buf);
+        })
+    });
+
+    group.bench_function("via_u64", |b| {
+        let mut rng = SquirrelRng::with_seed(3);
+        b.iter(|| {
+            fill_bytes_via_u64(&mut rng, &mut buf);
+            black_box(&buf);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, fill_bytes);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use squirrel_rng::SquirrelRng;
+
+fn next_u64(c: &mut Criterion) {
+    let mut group = c.benchmark_group("next_u64");
+
+    group.bench_function("via_u32", |b| {
+        let mut rng = SquirrelRng::with_seed(3);
+        b.iter(|| black_box(rng.next_u64()))
+    });
+
+    group.bench_function("fast", |b| {
+        let mut rng = SquirrelRng::with_seed(3);
+        b.iter(|| black_box(rng.next_u64_fast()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, next_u64);
+criterion_main!(benches);
@@ -0,0 +1,167 @@
+//! Constant-time sampling from a fixed discrete distribution.
+
+use alloc::{vec, vec::Vec};
+use core::convert::TryFrom;
+
+use crate::SquirrelRng;
+
+/// A precomputed table for picking indices in proportion to fixed weights in O(1).
+///
+/// This is Vose's alias method. Building the table takes O(n) time, after which every sample is
+/// one multiply and a comparison. Use it instead of
+/// [`weighted_index`](SquirrelRng::weighted_index) when the same weights are sampled many
+/// times.
+#[derive(Debug, Clone)]
+pub struct WeightedAliasTable {
+    /// For each column, the threshold (out of 2^32) below which the column itself is picked.
+    thresholds: Vec<u64>,
+    /// For each column, the index picked when the threshold is not met.
+    aliases: Vec<u32>,
+}
+
+impl WeightedAliasTable {
+    /// Builds a table for `weights`.
+    ///
+    /// The arithmetic is exact integer arithmetic, so the same weights always produce the same
+    /// table on every platform. Zero weights are never picked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty, all of its weights are zero, or it has more than
+    /// `u32::MAX` elements.
+    pub fn new(weights: &[u32]) -> Self {
+        let n = u32::try_from(weights.len()).expect("too many weights");
+        let total: u128 = weights.iter().map(|&weight| u128::from(weight)).sum();
+        assert!(
+            total > 0,
+            "weights must contain at least one nonzero weight"
+        );
+
+        // Each column holds `total` units; weight `w` is worth `w * n` of them.
+        let mut scaled: Vec<u128> = weights
+            .iter()
+            .map(|&weight| u128::from(weight) * u128::from(n))
+            .collect();
+        let mut thresholds = vec![1 << 32; weights.len()];
+        let mut aliases: Vec<u32> = (0..n).collect();
+
+        let (mut small, mut large): (Vec<u32>, Vec<u32>) =
+            (0..n).partition(|&i| scaled[i as usize] < total);
+
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            let (l, m) = (less as usize, more as usize);
+            thresholds[l] = ((scaled[l] << 32) / total) as u64;
+            aliases[l] = more;
+
+            scaled[m] = scaled[m] + scaled[l] - total;
+            if scaled[m] < total {
+                large.pop();
+                small.push(more);
+            }
+        }
+
+        // The arithmetic is exact, so the small list always runs out first and anything left in
+        // the large list is exactly full. It keeps the default threshold of 2^32 and always picks
+        // itself.
+        Self {
+            thresholds,
+            aliases,
+        }
+    }
+
+    /// Returns the number of weights the table was built from.
+    pub fn len(&self) -> usize {
+        self.thresholds.len()
+    }
+
+    /// Always `false`, since a table cannot be built from empty weights.
+    pub fn is_empty(&self) -> bool {
+        self.thresholds.is_empty()
+    }
+
+    /// Picks an index with probability proportional to its weight, consuming exactly one `u32`.
+    ///
+    /// The high part of `draw * len` selects the column, as in
+    /// [`gen_bounded`](SquirrelRng::gen_bounded), and the low part decides between the column and
+    /// its alias.
+    pub fn sample(&self, rng: &mut SquirrelRng) -> usize {
+        let product = u64::from(rng.next_u32()) * self.len() as u64;
+        let column = (product >> 32) as usize;
+        if u64::from(product as u32) < self.thresholds[column] {
+            column
+        } else {
+            self.aliases[column] as usize
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    use super::WeightedAliasTable;
+
+    #[test]
+    fn table_preserves_probability_mass() {
+        for weights in [
+            &[1, 0, 2, 3, 4][..],
+            &[5],
+            &[0, 0, 7],
+            &[u32::MAX, 1, u32::MAX],
+            &[1; 10],
+        ] {
+            let table = WeightedAliasTable::new(weights);
+            let n = weights.len() as u128;
+            let total: u128 = weights.iter().map(|&weight| u128::from(weight)).sum();
+
+            // Each column contributes its threshold to itself and the rest to its alias.
+            let mut mass = vec![0u128; weights.len()];
+            for (column, &threshold) in table.thresholds.iter().enumerate() {
+                assert!(threshold <= 1 << 32);
+                mass[column] += u128::from(threshold);
+                mass[table.aliases[column] as usize] += (1 << 32) - u128::from(threshold);
+            }
+
+            for (&weight, &mass) in weights.iter().zip(&mass) {
+                let expected = ((u128::from(weight) * n) << 32) / total;
+                assert!(expected.abs_diff(mass) <= n, "{:?}", weights);
+                if weight == 0 {
+                    assert_eq!(mass, 0, "{:?}", weights);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn samples_track_weights() {
+        let weights = [1, 0, 2, 3, 4];
+        let table = WeightedAliasTable::new(&weights);
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut counts = [0u32; 5];
+        for _ in 0..100_000 {
+            counts[table.sample(&mut rng)] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+        assert_eq!(rng.position(), 100_000);
+        let chi_square: f64 = weights
+            .iter()
+            .zip(counts)
+            .filter(|&(&weight, _)| weight > 0)
+            .map(|(&weight, count)| {
+                let expected = 100_000.0 * weight as f64 / 10.0;
+                (count as f64 - expected).powi(2) / expected
+            })
+            .sum();
+
+        // Three degrees of freedom; 16.27 is the 0.1% critical value.
+        assert!(chi_square < 16.27, "{}", chi_square);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_all_zero_weights() {
+        WeightedAliasTable::new(&[0, 0]);
+    }
+}
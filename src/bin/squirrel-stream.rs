@@ -0,0 +1,80 @@
+//! Writes an endless stream of `SquirrelRng` output to stdout.
+//!
+//! Intended for feeding statistical test suites:
+//!
+//! ```text
+//! squirrel-stream --seed 42 | RNG_test stdin32
+//! squirrel-stream --seed 42 --position 1000 | dieharder -a -g 200
+//! ```
+//!
+//! Both arguments are optional and accept decimal or `0x`-prefixed hex values. Without a seed,
+//! the rng is seeded from `thread_rng`.
+
+use std::{
+    env,
+    io::{self, Write},
+    process,
+};
+
+use squirrel_rng::SquirrelRng;
+
+fn main() {
+    let mut rng = match parse_args(env::args().skip(1)) {
+        Ok(rng) => rng,
+        Err(message) => {
+            eprintln!("squirrel-stream: {}", message);
+            eprintln!("usage: squirrel-stream [--seed <u32>] [--position <u32>]");
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = stream(&mut rng) {
+        // A closed pipe just means the consumer has seen enough.
+        if e.kind() != io::ErrorKind::BrokenPipe {
+            eprintln!("squirrel-stream: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<SquirrelRng, String> {
+    let mut seed = None;
+    let mut position = 0;
+
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--seed" | "--position" => args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", arg))?,
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        };
+        let value = parse_u32(&value).ok_or_else(|| format!("invalid value: {}", value))?;
+
+        if arg == "--seed" {
+            seed = Some(value);
+        } else {
+            position = value;
+        }
+    }
+
+    let rng = seed.map_or_else(SquirrelRng::new, SquirrelRng::with_seed);
+    Ok(rng.with_position(position))
+}
+
+fn parse_u32(s: &str) -> Option<u32> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+fn stream(rng: &mut SquirrelRng) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let mut buf = vec![0; 64 * 1024];
+
+    loop {
+        rng.fill_bytes(&mut buf);
+        stdout.write_all(&buf)?;
+    }
+}
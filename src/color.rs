@@ -0,0 +1,89 @@
+//! Random colors for debug visualization and procedural palettes.
+
+use crate::SquirrelRng;
+
+impl SquirrelRng {
+    /// Returns a random RGB color, consuming exactly one word.
+    ///
+    /// The channels are the three low bytes of the draw, red first.
+    #[inline]
+    pub fn next_color_rgb(&mut self) -> [u8; 3] {
+        let [r, g, b, _] = self.next_u32().to_le_bytes();
+        [r, g, b]
+    }
+
+    /// Returns a random vivid RGB color, consuming exactly one word.
+    ///
+    /// The color is picked in HSV space with a uniform hue, saturation of at least 75%, and value
+    /// of at least 88%, then converted to RGB with integer arithmetic. This avoids the muddy and
+    /// dark colors that plain [`next_color_rgb`](Self::next_color_rgb) often produces.
+    pub fn next_color_hsv_bright(&mut self) -> [u8; 3] {
+        let x = self.next_u32();
+        let hue = ((x >> 16) * 1536) >> 16;
+        let saturation = 192 + ((x >> 8) & 0xFF) / 4;
+        let value = 224 + (x & 0xFF) / 8;
+        hsv_to_rgb(hue, saturation, value)
+    }
+}
+
+/// Converts an HSV color to RGB.
+///
+/// `hue` is in `0..1536`, six sectors of 256 steps each; `saturation` and `value` are in
+/// `0..=255`.
+fn hsv_to_rgb(hue: u32, saturation: u32, value: u32) -> [u8; 3] {
+    let sector = hue / 256;
+    let f = hue % 256;
+
+    let p = value * (255 - saturation) / 255;
+    let q = value * (255 - saturation * f / 255) / 255;
+    let t = value * (255 - saturation * (255 - f) / 255) / 255;
+
+    let (r, g, b) = match sector {
+        0 => (value, t, p),
+        1 => (q, value, p),
+        2 => (p, value, t),
+        3 => (p, q, value),
+        4 => (t, p, value),
+        _ => (value, p, q),
+    };
+    [r as u8, g as u8, b as u8]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    use super::hsv_to_rgb;
+
+    #[test]
+    fn rgb_uses_one_word() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let expected = rng.peek().to_le_bytes();
+
+        assert_eq!(rng.next_color_rgb(), expected[..3]);
+        assert_eq!(rng.position(), 1);
+        assert_eq!(
+            SquirrelRng::with_seed(3).next_color_rgb(),
+            SquirrelRng::with_seed(3).next_color_rgb()
+        );
+    }
+
+    #[test]
+    fn hsv_colors_are_bright_and_saturated() {
+        assert_eq!(hsv_to_rgb(0, 255, 255), [255, 0, 0]);
+        assert_eq!(hsv_to_rgb(512, 255, 255), [0, 255, 0]);
+        assert_eq!(hsv_to_rgb(1024, 255, 255), [0, 0, 255]);
+        assert_eq!(hsv_to_rgb(700, 0, 200), [200, 200, 200]);
+
+        let mut rng = SquirrelRng::with_seed(3);
+        for _ in 0..10_000 {
+            let color = rng.next_color_hsv_bright();
+            let max = *color.iter().max().unwrap();
+            let min = *color.iter().min().unwrap();
+
+            assert!(max >= 224, "{:?}", color);
+            assert!(min <= 64, "{:?}", color);
+        }
+        assert_eq!(rng.position(), 10_000);
+    }
+}
@@ -0,0 +1,74 @@
+//! Reference generators for comparing against systems being migrated to squirrel.
+//!
+//! These are here so an existing stream can be reproduced next to a [`SquirrelRng`] in A/B
+//! tests without pulling in another crate. They are not recommended for new code.
+//!
+//! [`SquirrelRng`]: crate::SquirrelRng
+
+use crate::{fill_bytes_via_u32, next_u64_via};
+
+/// The 32-bit linear congruential generator from *Numerical Recipes*.
+///
+/// Each step computes `state = state * 1664525 + 1013904223` modulo 2^32 and returns the new
+/// state in full. Like every power-of-two LCG, the low bits are weak: bit `k` repeats with
+/// period `2^(k + 1)`, so the lowest bit simply alternates. Unlike [`SquirrelRng`], the stream
+/// is sequential, and there is no cheap way to seek to a position.
+///
+/// [`SquirrelRng`]: crate::SquirrelRng
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Lcg32 {
+    state: u32,
+}
+
+impl Lcg32 {
+    pub const MULTIPLIER: u32 = 1664525;
+    pub const INCREMENT: u32 = 1013904223;
+
+    pub const fn with_seed(seed: u32) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn state(&self) -> u32 {
+        self.state
+    }
+
+    /// Steps the generator and returns the new state.
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(Self::INCREMENT);
+        self.state
+    }
+
+    /// Returns the next two states as a `u64`, the first in the low half.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        next_u64_via(|| self.next_u32())
+    }
+
+    /// Fills `dest` with the next states, four little-endian bytes each.
+    #[inline]
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u32(dest, || self.next_u32());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lcg32;
+
+    #[test]
+    fn reference_sequence() {
+        let mut rng = Lcg32::with_seed(0);
+        assert_eq!(rng.next_u32(), 0x3C6EF35F);
+        assert_eq!(rng.next_u32(), 0x47502932);
+        assert_eq!(rng.next_u32(), 0xD1CCF6E9);
+        assert_eq!(rng.next_u32(), 0xAAF95334);
+        assert_eq!(rng.state(), 0xAAF95334);
+
+        let mut rng = Lcg32::with_seed(42);
+        assert_eq!(rng.next_u64(), 0x168F5CEC_4099B181);
+    }
+}
@@ -0,0 +1,110 @@
+//! Samplers for common non-uniform distributions. These need `std` for float functions such as
+//! `ln` and `cos`.
+
+use core::f64::consts::TAU;
+
+use crate::SquirrelRng;
+
+impl SquirrelRng {
+    /// Returns a normally distributed value with the given mean and standard deviation.
+    ///
+    /// Uses the Box-Muller transform over two `next_f64` draws (four positions). If the first
+    /// uniform is exactly zero, which would make its logarithm infinite, it is redrawn.
+    pub fn next_normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let u1 = loop {
+            let u = self.next_f64();
+            if u > 0.0 {
+                break u;
+            }
+        };
+        let u2 = self.next_f64();
+
+        let z = (-2.0 * u1.ln()).sqrt() * (TAU * u2).cos();
+        mean + std_dev * z
+    }
+
+    /// Returns an exponentially distributed value with rate `lambda` (and mean `1 / lambda`).
+    ///
+    /// Uses inverse-CDF sampling over one `next_f64` draw. The uniform is taken as `1 - u`, which
+    /// lies in `(0, 1]`, so its logarithm is always finite and no redraw is ever needed.
+    pub fn next_exponential(&mut self, lambda: f64) -> f64 {
+        -(1.0 - self.next_f64()).ln() / lambda
+    }
+
+    /// Returns a Poisson-distributed count with mean `lambda`.
+    ///
+    /// Uses Knuth's algorithm: uniforms are multiplied together until the product drops to
+    /// `e^-lambda` or below, and the count is the number of factors minus one. A draw returning
+    /// `k` consumes `k + 1` `next_f64`s, so the cost grows linearly with `lambda`; this is meant
+    /// for modest rates (up to a few dozen). For very large rates `e^-lambda` underflows and the
+    /// result is no longer accurate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lambda` is negative or not finite.
+    pub fn next_poisson(&mut self, lambda: f64) -> u32 {
+        assert!(
+            lambda >= 0.0 && lambda.is_finite(),
+            "rate must be finite and nonnegative"
+        );
+
+        let limit = (-lambda).exp();
+        let mut product = self.next_f64();
+        let mut count = 0;
+        while product > limit {
+            product *= self.next_f64();
+            count += 1;
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    fn mean_and_variance(samples: &[f64]) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        (mean, variance)
+    }
+
+    #[test]
+    fn normal_matches_parameters() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let samples: Vec<f64> = (0..100_000).map(|_| rng.next_normal(5.0, 2.0)).collect();
+        let (mean, variance) = mean_and_variance(&samples);
+
+        assert!((mean - 5.0).abs() < 0.05, "{}", mean);
+        assert!((variance - 4.0).abs() < 0.1, "{}", variance);
+    }
+
+    #[test]
+    fn exponential_mean_is_inverse_rate() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let samples: Vec<f64> = (0..100_000).map(|_| rng.next_exponential(4.0)).collect();
+        let (mean, _) = mean_and_variance(&samples);
+
+        assert!(samples.iter().all(|&x| x >= 0.0 && x.is_finite()));
+        assert!((mean - 0.25).abs() < 0.005, "{}", mean);
+    }
+
+    #[test]
+    fn poisson_matches_rate() {
+        for &lambda in &[0.5, 4.0, 20.0] {
+            let mut rng = SquirrelRng::with_seed(3);
+            let samples: Vec<f64> = (0..100_000)
+                .map(|_| rng.next_poisson(lambda) as f64)
+                .collect();
+            let (mean, variance) = mean_and_variance(&samples);
+
+            assert!((mean - lambda).abs() < lambda * 0.02, "{}", mean);
+            assert!((variance - lambda).abs() < lambda * 0.05, "{}", variance);
+        }
+
+        let mut rng = SquirrelRng::with_seed(3);
+        assert_eq!(rng.next_poisson(0.0), 0);
+        assert_eq!(rng.position(), 2);
+    }
+}
@@ -0,0 +1,205 @@
+//! Random points and directions for gamedev. These need `std` for trigonometry.
+
+use alloc::{vec, vec::Vec};
+use core::f32::consts::TAU;
+
+use crate::SquirrelRng;
+
+impl SquirrelRng {
+    /// Returns a uniformly random direction on the unit circle as `[cos, sin]`.
+    ///
+    /// Consumes exactly one word, which is mapped to an angle in `[0, 2π)`.
+    pub fn next_unit_vec2(&mut self) -> [f32; 2] {
+        let angle = self.next_f32() * TAU;
+        let (sin, cos) = angle.sin_cos();
+        [cos, sin]
+    }
+
+    /// Returns a point uniformly distributed inside a disc of the given radius, centered on the
+    /// origin.
+    ///
+    /// Consumes exactly two words: one for the distance and one for the angle. The distance is
+    /// `sqrt(u) * radius` rather than `u * radius`, since the latter would crowd points toward
+    /// the center.
+    pub fn next_in_circle(&mut self, radius: f32) -> [f32; 2] {
+        let distance = self.next_f32().sqrt() * radius;
+        let [x, y] = self.next_unit_vec2();
+        [x * distance, y * distance]
+    }
+
+    /// Returns a uniformly random direction on the unit sphere as `[x, y, z]`.
+    ///
+    /// Consumes exactly two words: `z` is uniform in `[-1, 1]` and the second word picks the
+    /// azimuth, with the `xy` radius set to `sqrt(1 - z^2)`. By Archimedes' hat-box theorem this
+    /// covers the sphere evenly, whereas picking a uniform latitude would crowd points at the
+    /// poles.
+    pub fn next_unit_vec3(&mut self) -> [f32; 3] {
+        let z = 1.0 - 2.0 * self.next_f32();
+        let radius = (1.0 - z * z).max(0.0).sqrt();
+        let [x, y] = self.next_unit_vec2();
+        [x * radius, y * radius, z]
+    }
+
+    /// Scatters points over `[0, width) x [0, height)` so that no two are closer than
+    /// `min_dist`, using Bridson's fast Poisson-disk sampling.
+    ///
+    /// Starting from one uniform point, each step picks a random active point and tries up to `k`
+    /// candidates in the annulus between `min_dist` and `2 * min_dist` around it. The first
+    /// candidate far enough from every existing point is added; if none is, the active point is
+    /// retired. The result is an even, natural-looking spread, as for placing trees or rocks.
+    /// Around 30 is a typical `k`; smaller values run faster but leave larger gaps.
+    ///
+    /// The number of words consumed depends on how the sampling unfolds, but the points are fully
+    /// determined by the starting position and seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width`, `height`, or `min_dist` is not positive and finite.
+    pub fn poisson_disk_2d(
+        &mut self,
+        width: f32,
+        height: f32,
+        min_dist: f32,
+        k: u32,
+    ) -> Vec<[f32; 2]> {
+        assert!(
+            width > 0.0 && width.is_finite() && height > 0.0 && height.is_finite(),
+            "area must be positive and finite"
+        );
+        assert!(
+            min_dist > 0.0 && min_dist.is_finite(),
+            "minimum distance must be positive and finite"
+        );
+
+        // A cell's diagonal is `min_dist`, so each cell holds at most one point, and any point
+        // closer than `min_dist` to a candidate lies within two cells of it.
+        let cell = min_dist / core::f32::consts::SQRT_2;
+        let columns = (width / cell).ceil() as usize;
+        let rows = (height / cell).ceil() as usize;
+        let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+        let cell_of = |[x, y]: [f32; 2]| {
+            (
+                ((x / cell) as usize).min(columns - 1),
+                ((y / cell) as usize).min(rows - 1),
+            )
+        };
+
+        let first = [
+            self.gen_range_f32(0.0..width),
+            self.gen_range_f32(0.0..height),
+        ];
+        let (column, row) = cell_of(first);
+        grid[row * columns + column] = Some(0);
+        let mut points = vec![first];
+        let mut active = vec![0];
+
+        while !active.is_empty() {
+            let slot = self.gen_bounded(active.len() as u32) as usize;
+            let [cx, cy] = points[active[slot]];
+
+            let found = (0..k).find_map(|_| {
+                let distance = min_dist * (1.0 + self.next_f32());
+                let [dx, dy] = self.next_unit_vec2();
+                let candidate = [cx + dx * distance, cy + dy * distance];
+                let [x, y] = candidate;
+                if !(0.0..width).contains(&x) || !(0.0..height).contains(&y) {
+                    return None;
+                }
+
+                let (column, row) = cell_of(candidate);
+                let too_close = (row.saturating_sub(2)..(row + 3).min(rows)).any(|r| {
+                    (column.saturating_sub(2)..(column + 3).min(columns)).any(|c| {
+                        grid[r * columns + c].is_some_and(|i| {
+                            let [px, py] = points[i];
+                            (px - x).powi(2) + (py - y).powi(2) < min_dist * min_dist
+                        })
+                    })
+                });
+                (!too_close).then_some((candidate, row * columns + column))
+            });
+
+            match found {
+                Some((candidate, index)) => {
+                    grid[index] = Some(points.len());
+                    active.push(points.len());
+                    points.push(candidate);
+                }
+                None => {
+                    active.swap_remove(slot);
+                }
+            }
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    #[test]
+    fn unit_vec2_has_unit_length() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for _ in 0..10_000 {
+            let [x, y] = rng.next_unit_vec2();
+            assert!(((x * x + y * y).sqrt() - 1.0).abs() < 1e-6);
+        }
+        assert_eq!(rng.position(), 10_000);
+    }
+
+    #[test]
+    fn in_circle_is_uniform_over_area() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut inner = 0;
+        for _ in 0..100_000 {
+            let [x, y] = rng.next_in_circle(2.0);
+            let distance = (x * x + y * y).sqrt();
+            assert!(distance <= 2.0 + 1e-6);
+            if distance < 1.0 {
+                inner += 1;
+            }
+        }
+
+        // The inner half of the radius covers a quarter of the area.
+        assert!((24_000..26_000).contains(&inner), "{}", inner);
+        assert_eq!(rng.position(), 200_000);
+    }
+
+    #[test]
+    fn unit_vec3_covers_the_sphere_evenly() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut polar = 0;
+        let mut positive_x = 0;
+        for _ in 0..100_000 {
+            let [x, y, z] = rng.next_unit_vec3();
+            assert!(((x * x + y * y + z * z).sqrt() - 1.0).abs() < 1e-5);
+            polar += (z.abs() > 0.9) as u32;
+            positive_x += (x > 0.0) as u32;
+        }
+
+        // The caps beyond |z| = 0.9 hold a tenth of the sphere's area.
+        assert!((9_500..10_500).contains(&polar), "{}", polar);
+        assert!((49_000..51_000).contains(&positive_x), "{}", positive_x);
+        assert_eq!(rng.position(), 200_000);
+    }
+
+    #[test]
+    fn poisson_disk_points_are_spread_out() {
+        let points = SquirrelRng::with_seed(3).poisson_disk_2d(50.0, 30.0, 2.0, 30);
+        assert_eq!(
+            points,
+            SquirrelRng::with_seed(3).poisson_disk_2d(50.0, 30.0, 2.0, 30)
+        );
+
+        for (i, &[x, y]) in points.iter().enumerate() {
+            assert!((0.0..50.0).contains(&x) && (0.0..30.0).contains(&y));
+            for &[px, py] in &points[..i] {
+                assert!((px - x).powi(2) + (py - y).powi(2) >= 4.0);
+            }
+        }
+
+        // A saturated disk packing with this spacing holds roughly 0.7 points per unit
+        // `min_dist^2` of area; far fewer would mean the sampler stopped early.
+        assert!(points.len() > 200, "{}", points.len());
+    }
+}
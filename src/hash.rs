@@ -0,0 +1,280 @@
+use core::hash::{BuildHasher, Hasher};
+
+use crate::{squirrel3, squirrel3_64, SquirrelRng};
+
+/// Splits `bytes` into little-endian words, zero-padding a trailing partial word.
+fn le_words(bytes: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    bytes.chunks(4).map(|chunk| {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        u32::from_le_bytes(word)
+    })
+}
+
+/// Mixes `word` into `hash`.
+///
+/// The word goes in on [`squirrel3`]'s seed side, which it reaches in full; the position side
+/// drops its top two bits. The running hash is passed as the position and also added to the
+/// seed, so the step computes the mixing of `hash * (0x68E31DA4 + 1) + word`. That multiplier is
+/// odd, so for a fixed word the step is a bijection of the hash, and for a fixed hash it is a
+/// bijection of the word: no bit of either is ever lost.
+#[inline]
+pub(crate) const fn fold_word(hash: u32, word: u32) -> u32 {
+    squirrel3(hash, hash.wrapping_add(word))
+}
+
+/// Folds `bytes` into `hash`, one little-endian word at a time.
+///
+/// A trailing partial word is zero-padded.
+pub(crate) fn fold_bytes(hash: u32, bytes: &[u8]) -> u32 {
+    le_words(bytes).fold(hash, fold_word)
+}
+
+/// Hashes a block of bytes, for example to detect accidental corruption of a generated blob.
+///
+/// Each little-endian four-byte chunk is mixed into a running hash that starts at `seed`, with
+/// a trailing partial chunk zero-padded. Inputs that differ only by trailing zero bytes within
+/// the last word therefore hash the same; include the length yourself if that matters.
+///
+/// The folding is exactly what [`SquirrelHasher::write`](Hasher::write) does from the same seed:
+/// each chunk enters [`squirrel3`] on the seed side, and the running hash is carried through a
+/// bijective step, so it keeps all 32 bits. Changing any one chunk therefore always changes that
+/// step's output. This is not a cryptographic hash, and collisions are easy to construct on
+/// purpose.
+pub fn squirrel3_hash_bytes(data: &[u8], seed: u32) -> u32 {
+    fold_bytes(seed, data)
+}
+
+/// A fast, non-cryptographic [`Hasher`] built on [`squirrel3`].
+///
+/// Written data is folded into the state four bytes at a time, with each word entering squirrel3 on
+/// the seed side so that every one of its bits counts. Integers are always read little-endian, so
+/// hashes are the same on every platform. This hasher offers no protection against deliberately
+/// colliding inputs; don't use it on untrusted keys.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SquirrelHasher {
+    hash: u32,
+}
+
+impl SquirrelHasher {
+    pub const fn with_seed(seed: u32) -> Self {
+        Self { hash: seed }
+    }
+}
+
+impl Default for SquirrelHasher {
+    fn default() -> Self {
+        Self::with_seed(0)
+    }
+}
+
+impl Hasher for SquirrelHasher {
+    /// Widens the 32-bit state to 64 bits.
+    ///
+    /// Hash tables such as `HashMap` take bits from both ends of the hash, so both halves need
+    /// to be well mixed.
+    fn finish(&self) -> u64 {
+        u64::from(squirrel3(1, self.hash)) << 32 | u64::from(squirrel3(0, self.hash))
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.hash = fold_bytes(self.hash, bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write_u32(u32::from(i));
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write_u32(u32::from(i));
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.hash = fold_word(self.hash, i);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write_u32(i as u32);
+        self.write_u32((i >> 32) as u32);
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// A [`BuildHasher`] producing [`SquirrelHasher`]s that all start from the same seed.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SquirrelBuildHasher {
+    seed: u32,
+}
+
+impl SquirrelBuildHasher {
+    pub const fn with_seed(seed: u32) -> Self {
+        Self { seed }
+    }
+}
+
+impl BuildHasher for SquirrelBuildHasher {
+    type Hasher = SquirrelHasher;
+
+    fn build_hasher(&self) -> SquirrelHasher {
+        SquirrelHasher::with_seed(self.seed)
+    }
+}
+
+impl SquirrelRng {
+    /// Maps `key` to a bucket in `0..num_buckets` with Lamping and Veach's jump consistent hash.
+    ///
+    /// The key is first mixed with [`squirrel3_64`] under this rng's seed, so different seeds
+    /// shard keys differently. Growing from `n` to `n + 1` buckets moves only about
+    /// `1 / (n + 1)` of the keys, all of them into the new bucket. This neither reads nor
+    /// advances the position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` is zero.
+    pub fn jump_consistent(&self, key: u64, num_buckets: u32) -> u32 {
+        assert!(num_buckets > 0, "there must be at least one bucket");
+
+        let mut key = squirrel3_64(key, u64::from(self.seed));
+        let mut bucket = 0;
+        let mut next: i64 = 0;
+        while next < i64::from(num_buckets) {
+            bucket = next;
+            key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+            next = ((bucket + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+        }
+        bucket as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        hash::{BuildHasher, Hash, Hasher},
+    };
+
+    use crate::SquirrelRng;
+
+    use super::{squirrel3_hash_bytes, SquirrelBuildHasher, SquirrelHasher};
+
+    fn hash_one(value: impl Hash) -> u64 {
+        let mut hasher = SquirrelHasher::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn hashes_are_deterministic_and_distinct() {
+        assert_eq!(hash_one("overworld"), hash_one("overworld"));
+        assert_ne!(hash_one("overworld"), hash_one("underworld"));
+        assert_ne!(hash_one(1u64), hash_one(2u64));
+        assert_ne!(hash_one((1u32, 2u32)), hash_one((2u32, 1u32)));
+
+        let a = SquirrelBuildHasher::with_seed(1).build_hasher().finish();
+        let b = SquirrelBuildHasher::with_seed(2).build_hasher().finish();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn works_as_hash_map_hasher() {
+        let mut map = HashMap::with_hasher(SquirrelBuildHasher::default());
+        for i in 0..1000 {
+            map.insert(i, i * 2);
+        }
+
+        assert_eq!(map.len(), 1000);
+        assert!((0..1000).all(|i| map[&i] == i * 2));
+    }
+
+    #[test]
+    fn jump_consistent_moves_few_keys() {
+        let rng = SquirrelRng::with_seed(3);
+        let mut moved = 0;
+        let mut counts = [0u32; 11];
+        for key in 0..100_000 {
+            let before = rng.jump_consistent(key, 10);
+            let after = rng.jump_consistent(key, 11);
+            assert!(before < 10);
+            counts[after as usize] += 1;
+            if before != after {
+                assert_eq!(after, 10);
+                moved += 1;
+            }
+        }
+
+        // About 1/11 of the keys move, and every bucket ends up with about 1/11 of them.
+        assert!((8_600..9_600).contains(&moved), "{}", moved);
+        assert!(
+            counts.iter().all(|count| (8_600..9_600).contains(count)),
+            "{:?}",
+            counts
+        );
+        assert_eq!(rng.jump_consistent(42, 1), 0);
+        assert_eq!(rng.position(), 0);
+    }
+
+    #[test]
+    fn hash_bytes_detects_single_byte_changes() {
+        let mut data = [0u8; 257];
+        SquirrelRng::with_seed(3).fill_bytes(&mut data);
+        let hash = squirrel3_hash_bytes(&data, 7);
+        assert_eq!(hash, squirrel3_hash_bytes(&data, 7));
+        assert_ne!(hash, squirrel3_hash_bytes(&data, 8));
+
+        let mut collisions = 0;
+        for i in 0..data.len() {
+            for flip in [1, 0x80, 0xFF] {
+                let mut changed = data;
+                changed[i] ^= flip;
+                collisions += usize::from(squirrel3_hash_bytes(&changed, 7) == hash);
+            }
+        }
+        assert_eq!(collisions, 0);
+    }
+
+    #[test]
+    fn high_bits_change_the_hash() {
+        for bit in [30, 31] {
+            assert_ne!(hash_one(0u32), hash_one(1u32 << bit), "bit {}", bit);
+            assert_ne!(hash_one(0u64), hash_one(1u64 << bit), "bit {}", bit);
+            assert_ne!(
+                hash_one(0u64),
+                hash_one(1u64 << (bit + 32)),
+                "bit {}",
+                bit + 32
+            );
+        }
+
+        // Keys that differ only in their top two bits all get distinct hashes.
+        let mut hashes: Vec<u64> = (0..250u32)
+            .flat_map(|low| (0..4u32).map(move |high| hash_one(high << 30 | low)))
+            .collect();
+        hashes.sort_unstable();
+        hashes.dedup();
+        assert_eq!(hashes.len(), 1000);
+    }
+
+    #[test]
+    fn hash_bytes_matches_hasher() {
+        let mut data = [0u8; 67];
+        SquirrelRng::with_seed(3).fill_bytes(&mut data);
+        for len in [0, 1, 4, 5, 67] {
+            let mut hasher = SquirrelHasher::with_seed(7);
+            hasher.write(&data[..len]);
+            assert_eq!(
+                squirrel3_hash_bytes(&data[..len], 7),
+                hasher.hash,
+                "length {}",
+                len
+            );
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use core::{iter::FusedIterator, ops::Range};
+
+use crate::{squirrel3, squirrel3_2d, SquirrelRng};
+
+/// An endless iterator over the `u32` output of a [`SquirrelRng`].
+///
+/// Created by [`SquirrelRng::iter_u32`].
+#[derive(Debug, Clone)]
+pub struct SquirrelIter {
+    rng: SquirrelRng,
+}
+
+impl SquirrelIter {
+    pub(crate) fn new(rng: SquirrelRng) -> Self {
+        Self { rng }
+    }
+
+    /// Returns the underlying rng, positioned after the last value yielded.
+    pub fn into_inner(self) -> SquirrelRng {
+        self.rng
+    }
+}
+
+impl Iterator for SquirrelIter {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        Some(self.rng.next_u32())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for SquirrelIter {}
+
+/// An endless iterator over the `u32` output of a borrowed [`SquirrelRng`].
+///
+/// Created by iterating over `&mut SquirrelRng`. The rng advances as values are yielded, so
+/// after the loop it picks up where the iterator left off. The stream never ends on its own;
+/// bound it with `take` or a `break`.
+#[derive(Debug)]
+pub struct SquirrelIterMut<'a> {
+    rng: &'a mut SquirrelRng,
+}
+
+impl Iterator for SquirrelIterMut<'_> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        Some(self.rng.next_u32())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for SquirrelIterMut<'_> {}
+
+impl<'a> IntoIterator for &'a mut SquirrelRng {
+    type Item = u32;
+    type IntoIter = SquirrelIterMut<'a>;
+
+    fn into_iter(self) -> SquirrelIterMut<'a> {
+        SquirrelIterMut { rng: self }
+    }
+}
+
+/// An iterator over the values at each position in a half-open range.
+///
+/// Created by [`SquirrelRng::range`]. Because every value depends only on its position, the
+/// range can be walked from either end.
+#[derive(Debug, Clone)]
+pub struct SquirrelRange {
+    positions: Range<u32>,
+    seed: u32,
+}
+
+impl SquirrelRange {
+    pub(crate) fn new(seed: u32, positions: Range<u32>) -> Self {
+        Self { positions, seed }
+    }
+}
+
+impl Iterator for SquirrelRange {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<u32> {
+        self.positions
+            .next()
+            .map(|position| squirrel3(position, self.seed))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for SquirrelRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<u32> {
+        self.positions
+            .next_back()
+            .map(|position| squirrel3(position, self.seed))
+    }
+}
+
+impl ExactSizeIterator for SquirrelRange {}
+
+impl FusedIterator for SquirrelRange {}
+
+/// Returns every cell of a `width` by `height` grid with its noise value, in row-major order.
+///
+/// Each item is `((x, y), squirrel3_2d(x, y, seed))`.
+pub fn grid_2d(seed: u32, width: u32, height: u32) -> impl Iterator<Item = ((u32, u32), u32)> {
+    (0..height).flat_map(move |y| {
+        (0..width).map(move |x| ((x, y), squirrel3_2d(x as i32, y as i32, seed)))
+    })
+}
+
+/// Alternates between the output of two rngs: `a`, `b`, `a`, `b`, and so on, without end.
+///
+/// Each rng advances by one for every value it contributes, so the first `2 * n` items are the
+/// first `n` values of each stream, interleaved.
+pub fn interleave(a: SquirrelRng, b: SquirrelRng) -> impl Iterator<Item = u32> {
+    a.iter_u32().zip(b.iter_u32()).flat_map(|(x, y)| [x, y])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{squirrel3_2d, SquirrelRng};
+
+    use super::{grid_2d, interleave};
+
+    #[test]
+    fn iter_matches_next_u32() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let values: Vec<u32> = rng.iter_u32().take(4).collect();
+
+        for value in values {
+            assert_eq!(value, rng.next_u32());
+        }
+    }
+
+    #[test]
+    fn borrowed_rng_iterates_in_place() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut expected = rng;
+        let mut values = Vec::new();
+        for value in &mut rng {
+            values.push(value);
+            if values.len() == 4 {
+                break;
+            }
+        }
+
+        for value in values {
+            assert_eq!(value, expected.next_u32());
+        }
+        assert_eq!(rng, expected);
+    }
+
+    #[test]
+    fn range_is_reversible() {
+        let forward: Vec<u32> = SquirrelRng::range(3, 10..20).collect();
+        let mut backward: Vec<u32> = SquirrelRng::range(3, 10..20).rev().collect();
+        backward.reverse();
+
+        assert_eq!(forward.len(), 10);
+        assert_eq!(forward, backward);
+        assert_eq!(forward[0], SquirrelRng::with_seed(3).sample_at(10));
+    }
+
+    #[test]
+    fn grid_2d_is_row_major() {
+        let cells: Vec<_> = grid_2d(3, 4, 3).collect();
+
+        assert_eq!(cells.len(), 12);
+        assert_eq!(cells[1].0, (1, 0));
+        assert_eq!(cells[6], ((2, 1), squirrel3_2d(2, 1, 3)));
+    }
+
+    #[test]
+    fn interleave_alternates_sources() {
+        let a = SquirrelRng::with_seed(3);
+        let b = SquirrelRng::with_seed(4);
+        let values: Vec<u32> = interleave(a, b).take(4).collect();
+
+        assert_eq!(
+            values,
+            [
+                a.sample_at(0),
+                b.sample_at(0),
+                a.sample_at(1),
+                b.sample_at(1)
+            ]
+        );
+        assert_eq!(values, interleave(a, b).take(4).collect::<Vec<_>>());
+    }
+}
@@ -1,36 +1,1004 @@
+//! An implementation of `rand::Rng` based on Squirrel Eiserloh's noise-based rng, as presented
+//! in his talk on Math for Game Programmers.
+//!
+//! # Stability
+//!
+//! The output of [`squirrel3`] for a given `(position, seed)` pair is part of this crate's public
+//! contract and will not change in any future release, including major ones. The same goes for
+//! everything built directly on it: the `u32` stream of [`SquirrelRng`], and the way `next_u64`
+//! and `fill_bytes` assemble that stream (little-endian, low word first, whatever the native
+//! byte order of the platform). Values baked into
+//! saved worlds or test fixtures will stay valid. Golden values in the test suite enforce this.
+//! [`squirrel5`] and [`SquirrelRng5`] carry the same guarantee.
+
 #![cfg_attr(all(not(test), not(feature = "std")), no_std)]
+#![cfg_attr(feature = "nightly-simd", feature(portable_simd))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+use core::{
+    fmt,
+    num::NonZeroU32,
+    ops::{Range, RangeInclusive},
+};
 
+use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "getrandom")]
 use rand::rngs::OsRng;
+// Re-exported below when `rand-reexport` is on.
+#[cfg(not(feature = "rand-reexport"))]
+use rand::{Rng, RngCore};
 
 #[cfg(feature = "std")]
 use rand::rngs::ThreadRng;
 
+#[cfg(feature = "alloc")]
+mod alias;
+mod color;
+pub mod compat;
+#[cfg(feature = "std")]
+mod distributions;
+#[cfg(feature = "std")]
+mod geometry;
+mod hash;
+mod iter;
+#[cfg(feature = "alloc")]
+mod markov;
+#[cfg(feature = "std")]
+mod noise;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(test)]
+mod quality;
+#[cfg(feature = "rand_06")]
+mod rand06;
+#[cfg(feature = "rand_09")]
+mod rand09;
+mod seq;
+mod simd;
+mod source;
+mod squirrel5;
+mod squirrel64;
+pub mod stable;
+#[cfg(feature = "alloc")]
+mod text;
+#[cfg(feature = "std")]
+mod thread;
+mod word;
+
+#[cfg(feature = "alloc")]
+pub use alias::WeightedAliasTable;
+pub use hash::{squirrel3_hash_bytes, SquirrelBuildHasher, SquirrelHasher};
+pub use iter::{grid_2d, interleave, SquirrelIter, SquirrelIterMut, SquirrelRange};
+#[cfg(feature = "alloc")]
+pub use markov::MarkovChain;
+#[cfg(feature = "std")]
+pub use noise::{fbm_2d, perlin_2d, value_noise_2d, worley_2d};
+#[cfg(feature = "rayon")]
+pub use parallel::{fill_parallel, par_map};
+#[cfg(feature = "rand-reexport")]
 pub use rand::{Rng, RngCore, SeedableRng};
+pub use simd::squirrel3_x4;
+pub use source::Squirrel;
+pub use squirrel5::{squirrel5, SquirrelRng5};
+pub use squirrel64::{squirrel3_64, SquirrelRng64};
+#[cfg(feature = "alloc")]
+pub use text::TOKEN_ALPHABET;
+#[cfg(feature = "std")]
+pub use thread::{squirrel_rng, SquirrelThreadRng};
+pub use word::SquirrelWord;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// A counter-based rng whose stream is determined by a position and a seed.
+///
+/// States order by position first, seed second, and [overflow policy](OverflowPolicy) last. The
+/// ordering carries no meaning beyond letting states be sorted or used as keys in ordered
+/// collections.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SquirrelRng {
     position: u32,
     seed: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "OverflowPolicy::is_wrap")
+    )]
+    overflow: OverflowPolicy,
+}
+
+/// What a [`SquirrelRng`] does when a draw would move its position past `u32::MAX`.
+///
+/// The policy applies to draws only. Explicit seeks such as [`jump`](SquirrelRng::jump),
+/// [`skip_ahead`](SquirrelRng::skip_ahead), and [`prev_u32`](SquirrelRng::prev_u32) always wrap.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OverflowPolicy {
+    /// Wrap around to position 0, the same as every other stepping method.
+    #[default]
+    Wrap,
+    /// Stay at `u32::MAX`, so every further draw returns the value there.
+    Saturate,
+    /// Panic on a draw at `u32::MAX` when debug assertions are enabled. Release builds wrap
+    /// around to position 0, like [`Wrap`](OverflowPolicy::Wrap).
+    Panic,
+}
+
+impl OverflowPolicy {
+    #[cfg(feature = "serde")]
+    fn is_wrap(&self) -> bool {
+        *self == OverflowPolicy::Wrap
+    }
 }
 
 impl SquirrelRng {
+    /// Creates an rng seeded from `thread_rng`.
     #[cfg(feature = "std")]
     pub fn new() -> Self {
         Self::seed_from(rand::thread_rng())
     }
 
+    /// Creates an rng seeded directly from the operating system's entropy source.
+    ///
+    /// Unlike [`new`](Self::new), this bypasses the thread-local generator entirely.
+    #[cfg(feature = "getrandom")]
+    pub fn from_entropy() -> Self {
+        Self::seed_from(OsRng)
+    }
+
+    /// Creates an rng seeded from the system clock.
+    ///
+    /// The nanoseconds since the Unix epoch are folded into a `u32` with [`combine_seeds`]. The
+    /// seed is easy to log (see [`seed`](Self::seed)) and replay, but it is also easy to guess,
+    /// so this is not suitable for anything security sensitive. A clock set before the epoch
+    /// counts as zero. On targets without a system clock, such as `wasm32-unknown-unknown`,
+    /// `SystemTime::now` panics.
+    #[cfg(feature = "std")]
+    pub fn from_time() -> Self {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let words = [nanos as u32, (nanos >> 32) as u32, (nanos >> 64) as u32];
+        Self::with_seed(combine_seeds(&words))
+    }
+
+    /// Creates an rng seeded from the next `u32` of `rng`.
+    ///
+    /// `&mut R` implements `RngCore` too, so a borrowed generator can be passed here as well; see
+    /// also [`seed_from_mut`](Self::seed_from_mut).
     pub fn seed_from(mut rng: impl Rng) -> Self {
         Self::with_seed(rng.next_u32())
     }
 
-    pub fn with_seed(seed: u32) -> Self {
-        Self { position: 0, seed }
+    /// Creates an rng seeded from the next `u32` of a borrowed generator.
+    pub fn seed_from_mut<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self::with_seed(rng.next_u32())
+    }
+
+    pub const fn with_seed(seed: u32) -> Self {
+        Self::from_state(0, seed)
+    }
+
+    /// Reconstructs an rng from a previously saved position and seed.
+    pub const fn from_state(position: u32, seed: u32) -> Self {
+        Self {
+            position,
+            seed,
+            overflow: OverflowPolicy::Wrap,
+        }
+    }
+
+    /// Reconstructs an rng from a `(position, seed)` pair returned by [`state`](Self::state).
+    pub const fn from_state_tuple((position, seed): (u32, u32)) -> Self {
+        Self::from_state(position, seed)
+    }
+
+    /// Restores an rng from the layout produced by [`to_bytes`](Self::to_bytes).
+    pub const fn from_bytes(bytes: [u8; 8]) -> Self {
+        let [p0, p1, p2, p3, s0, s1, s2, s3] = bytes;
+        Self::from_state(
+            u32::from_le_bytes([p0, p1, p2, p3]),
+            u32::from_le_bytes([s0, s1, s2, s3]),
+        )
+    }
+
+    /// Packs the position and seed into eight bytes.
+    ///
+    /// The first four bytes are the position and the last four are the seed, each as a
+    /// little-endian `u32`. This layout is stable and independent of the platform. The
+    /// [overflow policy](OverflowPolicy) is not included; [`from_bytes`](Self::from_bytes) always
+    /// restores [`OverflowPolicy::Wrap`].
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        let [p0, p1, p2, p3] = self.position.to_le_bytes();
+        let [s0, s1, s2, s3] = self.seed.to_le_bytes();
+        [p0, p1, p2, p3, s0, s1, s2, s3]
+    }
+
+    /// Creates an rng from a 64-bit seed by mixing both halves into the 32-bit seed.
+    ///
+    /// The high word is multiplied by an odd constant before being xor-ed into the low word, so
+    /// seeds that differ in only one half never collide. A seed whose high word is zero produces
+    /// the same rng as `with_seed` on the low word.
+    pub const fn with_seed_u64(seed: u64) -> Self {
+        let low = seed as u32;
+        let high = (seed >> 32) as u32;
+        Self::with_seed(low ^ high.wrapping_mul(0x9E3779B9))
+    }
+
+    /// Creates an rng whose seed is the 32-bit FNV-1a hash of `bytes`.
+    ///
+    /// Bytes are consumed one at a time in slice order, so the mapping does not depend on the
+    /// platform's endianness and is stable across versions.
+    pub const fn seed_from_bytes(bytes: &[u8]) -> Self {
+        const FNV_OFFSET_BASIS: u32 = 0x811C9DC5;
+        const FNV_PRIME: u32 = 0x01000193;
+
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u32;
+            hash = hash.wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        Self::with_seed(hash)
     }
 
-    pub fn with_position(self, position: u32) -> Self {
+    pub const fn with_position(self, position: u32) -> Self {
         Self { position, ..self }
     }
+
+    /// Returns a copy of this rng with `seed` swapped in, keeping the current position.
+    ///
+    /// This is the complement of [`with_position`](Self::with_position): the copy walks a
+    /// different stream in lockstep with this one.
+    pub const fn with_different_seed(&self, seed: u32) -> Self {
+        Self { seed, ..*self }
+    }
+
+    /// Returns a copy of this rng that handles position overflow according to `policy`.
+    ///
+    /// The default is [`OverflowPolicy::Wrap`]. The other policies let long-running simulations
+    /// notice when a stream is exhausted instead of silently replaying it.
+    pub const fn with_overflow_policy(self, policy: OverflowPolicy) -> Self {
+        Self {
+            overflow: policy,
+            ..self
+        }
+    }
+
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow
+    }
+
+    pub fn set_position(&mut self, position: u32) {
+        self.position = position;
+    }
+
+    /// Replaces the seed and rewinds to position 0, as if freshly built with `with_seed`.
+    pub fn reseed(&mut self, seed: u32) {
+        self.seed = seed;
+        self.position = 0;
+    }
+
+    /// Replaces the seed but leaves the position where it is.
+    ///
+    /// Unlike [`reseed`](Self::reseed), the position keeps counting up across seed changes, so
+    /// rotating seeds within a session never pairs a seed with a position it was used at before.
+    pub fn reseed_keep_position(&mut self, seed: u32) {
+        self.seed = seed;
+    }
+
+    /// Moves the position by `delta`, which may be negative.
+    ///
+    /// The position wraps at the `u32` boundary in both directions, so jumping back one from
+    /// position 0 lands on `u32::MAX`. Deltas larger than the position space are effectively
+    /// taken modulo 2^32.
+    pub fn jump(&mut self, delta: i64) {
+        self.position = self.position.wrapping_add(delta as u32);
+    }
+
+    /// Advances the position by `n` without generating anything.
+    ///
+    /// Like [`jump`](Self::jump), this wraps at the `u32` boundary, so only `n` modulo 2^32
+    /// matters.
+    pub fn skip_ahead(&mut self, n: u64) {
+        self.position = self.position.wrapping_add(n as u32);
+    }
+
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Returns whether both rngs have the same seed, whatever their positions.
+    ///
+    /// `==` compares positions as well; this is for grouping rngs that walk the same stream.
+    pub fn same_seed(&self, other: &SquirrelRng) -> bool {
+        self.seed == other.seed
+    }
+
+    /// Returns the position and seed as `(position, seed)`.
+    pub const fn state(&self) -> (u32, u32) {
+        (self.position, self.seed)
+    }
+
+    /// Returns how many draws are left before the position wraps back to 0.
+    ///
+    /// This is `u32::MAX - position`, the same count [`try_next_u32`](Self::try_next_u32) will
+    /// yield before returning `None`. Keep in mind that [`squirrel3`] itself repeats every 2^30
+    /// positions, so a stream longer than that revisits earlier values before it runs out.
+    pub fn remaining(&self) -> u32 {
+        u32::MAX - self.position
+    }
+
+    /// Returns the position this rng would reach after drawing `n` words, without drawing them.
+    ///
+    /// This is `position + n`, wrapping at the `u32` boundary as [`skip_ahead`](Self::skip_ahead)
+    /// does. It does not account for an [overflow policy](OverflowPolicy) other than `Wrap`.
+    pub const fn position_after(&self, n: u32) -> u32 {
+        self.position.wrapping_add(n)
+    }
+
+    /// Moves past the position just drawn from, applying the overflow policy at `u32::MAX`.
+    #[inline]
+    fn advance(&mut self) {
+        self.position = match self.position.checked_add(1) {
+            Some(next) => next,
+            None => match self.overflow {
+                OverflowPolicy::Panic if cfg!(debug_assertions) => {
+                    panic!("SquirrelRng position overflowed")
+                }
+                OverflowPolicy::Wrap | OverflowPolicy::Panic => 0,
+                OverflowPolicy::Saturate => u32::MAX,
+            },
+        };
+    }
+
+    /// Returns the value at `position` for this rng's seed.
+    ///
+    /// This does not advance (or otherwise touch) the rng's own position.
+    #[inline]
+    pub fn sample_at(&self, position: u32) -> u32 {
+        squirrel3(position, self.seed)
+    }
+
+    /// Returns the values at each of `positions`, as [`sample_at`](Self::sample_at) would.
+    ///
+    /// Like `sample_at`, this leaves the rng untouched.
+    #[inline]
+    pub fn probe<const N: usize>(&self, positions: [u32; N]) -> [u32; N] {
+        positions.map(|position| self.sample_at(position))
+    }
+
+    /// Returns the value the next call to `next_u32` will produce, without advancing.
+    #[inline]
+    pub fn peek(&self) -> u32 {
+        self.sample_at(self.position)
+    }
+
+    /// Returns the value `n` draws ahead of the current position, without advancing.
+    ///
+    /// `nth(0)` is the same as [`peek`](Self::peek). Unlike `Iterator::nth`, this is O(1).
+    #[inline]
+    pub fn nth(&self, n: u32) -> u32 {
+        self.sample_at(self.position.wrapping_add(n))
+    }
+
+    /// Spawns a child rng seeded from the next value of this one.
+    ///
+    /// The child starts at position 0. Because each split consumes a draw from the parent,
+    /// repeated splits yield children with distinct seeds, and the whole family is reproducible
+    /// from the parent's state.
+    pub fn split(&mut self) -> SquirrelRng {
+        Self::with_seed(self.next_u32())
+    }
+
+    /// Like `next_u32`, but returns `None` instead of letting the position wrap.
+    ///
+    /// Once the position reaches `u32::MAX`, this returns `None` and leaves the rng untouched,
+    /// so a stream started at position 0 never revisits a position. (The value at `u32::MAX`
+    /// itself is therefore never produced by this method.) Note that [`squirrel3`] repeats its
+    /// output every 2^30 positions; [`SquirrelRng5`] does not have that limitation.
+    #[inline]
+    pub fn try_next_u32(&mut self) -> Option<u32> {
+        let next = self.position.checked_add(1)?;
+        let result = self.sample_at(self.position);
+        self.position = next;
+        Some(result)
+    }
+
+    /// Steps back one position and returns the value there, undoing the last `next_u32`.
+    ///
+    /// Walking backward with this method yields the stream in reverse, so `next_u32` followed by
+    /// `prev_u32` returns the same value twice and leaves the rng where it started. The position
+    /// wraps from 0 to `u32::MAX`.
+    #[inline]
+    pub fn prev_u32(&mut self) -> u32 {
+        self.position = self.position.wrapping_sub(1);
+        self.sample_at(self.position)
+    }
+
+    /// Like `next_u32`, but also returns the position the value was drawn from.
+    ///
+    /// The result is `(position, value)`, where `value` is `squirrel3(position, seed)`.
+    #[inline]
+    pub fn next_with_position(&mut self) -> (u32, u32) {
+        let position = self.position;
+        (position, self.next_u32())
+    }
+
+    /// Returns the next nonzero `u32`.
+    ///
+    /// This is one draw in all but one case in 2^32: if a draw comes up zero, it is skipped and
+    /// the next is taken, so the position can advance by more than one.
+    pub fn next_nonzero_u32(&mut self) -> NonZeroU32 {
+        loop {
+            if let Some(value) = NonZeroU32::new(self.next_u32()) {
+                return value;
+            }
+        }
+    }
+
+    /// Returns a random byte, consuming one whole `u32`.
+    ///
+    /// The remaining bits of the draw are discarded rather than buffered. Buffering would make
+    /// the output depend on more than `(position, seed)`, breaking seeking, `peek`, and the
+    /// eight-byte state layout; callers who want every byte should use `fill_bytes` instead.
+    #[inline]
+    pub fn next_u8(&mut self) -> u8 {
+        (self.next_u32() >> 24) as u8
+    }
+
+    /// Returns a random byte with exactly four bits set, for dithering masks.
+    ///
+    /// Each draw is split into its four little-endian bytes, and the first with four bits set is
+    /// returned; if none has, the next word is drawn. The result is uniform over the 70 such
+    /// bytes. About 27% of bytes qualify, so a word is rejected with probability about 0.28 and
+    /// a call consumes 1.39 words on average. Like every rejection loop, the count is unbounded
+    /// in principle, but more than ten words is already rarer than one in 300,000.
+    pub fn next_balanced_byte(&mut self) -> u8 {
+        loop {
+            let bytes = self.next_u32().to_le_bytes();
+            if let Some(&byte) = bytes.iter().find(|byte| byte.count_ones() == 4) {
+                return byte;
+            }
+        }
+    }
+
+    /// Returns a random `u16`, consuming one whole `u32`.
+    ///
+    /// As with [`next_u8`](Self::next_u8), the unused half of the draw is discarded.
+    #[inline]
+    pub fn next_u16(&mut self) -> u16 {
+        (self.next_u32() >> 16) as u16
+    }
+
+    /// Returns 128 random bits built from four `next_u32` draws.
+    ///
+    /// Words are combined little-endian: the first draw supplies the lowest 32 bits and the
+    /// fourth the highest, matching the ordering of `next_u64`.
+    #[inline]
+    pub fn next_u128(&mut self) -> u128 {
+        let low = u128::from(self.next_u64());
+        let high = u128::from(self.next_u64());
+        (high << 64) | low
+    }
+
+    /// Returns two copies of this rng, with the same seed, position and overflow policy.
+    ///
+    /// Each copy advances on its own, so two consumers can walk the same stream independently,
+    /// for instance to run two algorithms over the same randomness. This is just two copies of
+    /// a `Copy` type, named for intent; use [`fork`](Self::fork) instead for a different stream.
+    pub const fn tee(&self) -> (SquirrelRng, SquirrelRng) {
+        (*self, *self)
+    }
+
+    /// Returns a child rng derived from the current state, without advancing this one.
+    ///
+    /// The child is seeded with [`peek`](Self::peek) and starts at position 0: it is exactly the
+    /// rng [`split`](Self::split) would return, except that the parent does not move. Forking an
+    /// unchanged parent again yields the same child, whereas every split yields a new one.
+    pub fn fork(&self) -> SquirrelRng {
+        Self::with_seed(self.peek())
+    }
+
+    /// Returns an independent rng for the stream named `id`.
+    ///
+    /// The child is seeded with `squirrel3(id, seed)` and starts at position 0. Unlike
+    /// [`fork`](Self::fork), it depends only on this rng's seed and not on its position, so one
+    /// master seed can hand out a fixed rng per subsystem (physics, loot, and so on). Hashing the
+    /// id avoids the correlation between neighboring seeds that `with_seed(seed + id)` would have.
+    pub fn stream(&self, id: u32) -> SquirrelRng {
+        Self::with_seed(squirrel3(id, self.seed))
+    }
+
+    /// Returns the rng for chunk `(chunk_x, chunk_y)` of a world, at position 0.
+    ///
+    /// The chunk's seed is `squirrel3_2d(chunk_x, chunk_y, world_seed)`, so a chunk regenerates
+    /// identically no matter when or in what order it is visited, and neighboring chunks get
+    /// unrelated streams.
+    pub const fn chunk_rng(world_seed: u32, chunk_x: i32, chunk_y: i32) -> SquirrelRng {
+        Self::with_seed(squirrel3_2d(chunk_x, chunk_y, world_seed))
+    }
+
+    /// Returns a uniformly distributed value in `[0, 1)`, consuming one `u32`.
+    ///
+    /// Only the top 24 bits of the draw are used, which is exactly the precision of an `f32`
+    /// mantissa; this guarantees the result can never round up to 1.0.
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        u32_to_unit_f32(self.next_u32())
+    }
+
+    /// Returns a value in `[range.start, range.end)`, consuming exactly one `u32`.
+    ///
+    /// The draw is a single [`next_f32`](Self::next_f32) scaled linearly onto the range. If float
+    /// rounding would land on `range.end`, the largest float below it is returned instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, reversed, or too wide for its length to be finite.
+    pub fn gen_range_f32(&mut self, range: Range<f32>) -> f32 {
+        let Range { start, end } = range;
+        assert!(start < end, "cannot sample empty range");
+        let span = end - start;
+        assert!(span.is_finite(), "range is too wide to sample");
+
+        let value = start + self.next_f32() * span;
+        if value < end {
+            value
+        } else {
+            next_down_f32(end)
+        }
+    }
+
+    /// Returns the value at the current position, then advances by one.
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        let result = squirrel3(self.position, self.seed);
+        self.advance();
+        result
+    }
+
+    /// Returns the next two values as a `u64`, the first in the low half.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        next_u64_via(|| self.next_u32())
+    }
+
+    /// Fills `dest` with the next values, four little-endian bytes each.
+    ///
+    /// A trailing partial word takes the low bytes of one more value.
+    #[inline]
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u32(dest, || self.next_u32());
+    }
+
+    /// Returns 64 random bits from a single hash, consuming one position.
+    ///
+    /// `next_u64` spends two [`squirrel3`] evaluations per value; this applies [`squirrel3_64`]
+    /// once to the position and seed widened to 64 bits, roughly halving the cost for code that
+    /// only wants `u64`s. The values form a different stream: they are not the
+    /// [`next_u64_via_u32`] concatenation of two words, and are unrelated to the `u32` output at
+    /// the same positions. They match [`SquirrelRng64`] built from the same seed and position.
+    #[inline]
+    pub fn next_u64_fast(&mut self) -> u64 {
+        let result = squirrel3_64(u64::from(self.position), u64::from(self.seed));
+        self.advance();
+        result
+    }
+
+    /// Returns a uniformly distributed value in `[0, 1)`, consuming one `u64` (two positions).
+    ///
+    /// The top 53 bits of the draw fill the `f64` mantissa.
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns an antithetic pair `(u, 1.0 - u)`, where `u` is exactly what
+    /// [`next_f64`](Self::next_f64) would return.
+    ///
+    /// Averaging an estimate over both halves of each pair cancels much of the variance of a
+    /// Monte Carlo integral whose integrand is monotonic, for the cost of one `u64`. The halves
+    /// are perfectly negatively correlated, so treat the pair as one sample, not two. `u` is in
+    /// `[0, 1)` and `1.0 - u` is in `(0, 1]`.
+    #[inline]
+    pub fn next_f64_antithetic(&mut self) -> (f64, f64) {
+        let u = self.next_f64();
+        (u, 1.0 - u)
+    }
+
+    /// Returns `true` with the given probability, consuming exactly one `u32`.
+    ///
+    /// `probability` is clamped to `[0, 1]` (NaN counts as 0). A probability of 0 always yields
+    /// `false` and a probability of 1 always yields `true`.
+    pub fn next_bool(&mut self, probability: f32) -> bool {
+        let value = self.next_u32();
+        if probability >= 1.0 {
+            true
+        } else if probability > 0.0 {
+            value < (probability as f64 * u32::MAX as f64) as u32
+        } else {
+            false
+        }
+    }
+
+    /// Returns `true` or `false` with exactly even odds, consuming exactly one `u32`.
+    ///
+    /// This is the top bit of the draw, so no float math is involved.
+    #[inline]
+    pub fn flip(&mut self) -> bool {
+        self.next_u32() >> 31 == 1
+    }
+
+    /// Returns `1` or `-1` with exactly even odds, consuming exactly one `u32`.
+    ///
+    /// The sign comes from the top bit of the draw, as in [`flip`](Self::flip): a set bit gives
+    /// `-1`.
+    #[inline]
+    pub fn gen_sign(&mut self) -> i32 {
+        1 - 2 * (self.next_u32() >> 31) as i32
+    }
+
+    /// Like [`gen_sign`](Self::gen_sign), but returns `1.0` or `-1.0`.
+    #[inline]
+    pub fn gen_sign_f32(&mut self) -> f32 {
+        self.gen_sign() as f32
+    }
+
+    /// Returns a value in `[0, bound)`, consuming exactly one `u32`.
+    ///
+    /// Unlike `Rng::gen_range`, which rejects and redraws to remove bias, this always advances
+    /// the position by one, so the result at a given position and seed never depends on the
+    /// bound being sampled before it. The price is a tiny bias: using Lemire's multiply-shift
+    /// mapping, some outputs are produced by one more of the 2^32 inputs than others, a relative
+    /// error of at most `bound / 2^32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    #[inline]
+    pub fn gen_bounded(&mut self, bound: u32) -> u32 {
+        assert!(bound > 0, "bound must be nonzero");
+        ((u64::from(self.next_u32()) * u64::from(bound)) >> 32) as u32
+    }
+
+    /// Returns a value in `[0, 2^bits)`, consuming exactly one `u32`.
+    ///
+    /// This keeps the top `bits` bits of the draw, which is exactly uniform and gives the same
+    /// result as `gen_bounded(1 << bits)` without the multiply. Zero bits always yields 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than 32.
+    #[inline]
+    pub fn gen_bounded_pow2(&mut self, bits: u32) -> u32 {
+        assert!(bits <= 32, "cannot draw more than 32 bits");
+        self.next_u32().checked_shr(32 - bits).unwrap_or(0)
+    }
+
+    /// Returns a value in `[range.start, range.end)`, consuming exactly one `u32`.
+    ///
+    /// The span is computed in the unsigned domain, so any nonempty range works, including
+    /// `i32::MIN..i32::MAX`. The value is mapped with [`gen_bounded`](Self::gen_bounded) and
+    /// shares its tiny bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn gen_range_i32(&mut self, range: Range<i32>) -> i32 {
+        let Range { start, end } = range;
+        assert!(start < end, "cannot sample empty range");
+        let span = end.wrapping_sub(start) as u32;
+        start.wrapping_add(self.gen_bounded(span) as i32)
+    }
+
+    /// Returns a value in `[range.start(), range.end()]`, consuming exactly one `u32`.
+    ///
+    /// The span is computed as a `u64`, so the full range `0..=u32::MAX`, whose span of 2^32
+    /// does not fit in a `u32`, is handled and simply returns the draw itself. Like
+    /// [`gen_bounded`](Self::gen_bounded), this uses multiply-shift and shares its tiny bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn gen_range_inclusive(&mut self, range: RangeInclusive<u32>) -> u32 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot sample empty range");
+        let span = u64::from(end - start) + 1;
+        start + ((u64::from(self.next_u32()) * span) >> 32) as u32
+    }
+
+    /// Returns a newly allocated vector of `n` bytes, filled exactly as `fill_bytes` would.
+    #[cfg(feature = "alloc")]
+    pub fn gen_bytes(&mut self, n: usize) -> Vec<u8> {
+        let mut bytes = vec![0; n];
+        self.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    /// Returns the next `n` values of `next_u32` as a vector, advancing the position by `n`.
+    #[cfg(feature = "alloc")]
+    pub fn take_u32(&mut self, n: usize) -> Vec<u32> {
+        let mut values = vec![0; n];
+        self.fill_u32_slice(&mut values);
+        values
+    }
+
+    /// Draws `samples` values with [`gen_bounded`](Self::gen_bounded) and returns how many fell
+    /// into each of `buckets` equal-width buckets.
+    ///
+    /// This is a quick uniformity check for tests, without reaching for a stats crate. The
+    /// position advances by exactly `samples`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buckets` is zero or greater than `u32::MAX`.
+    #[cfg(feature = "alloc")]
+    pub fn histogram(&mut self, buckets: usize, samples: usize) -> Vec<usize> {
+        let bound = u32::try_from(buckets).expect("too many buckets");
+        assert!(bound > 0, "histogram needs at least one bucket");
+        let mut counts = vec![0; buckets];
+        for _ in 0..samples {
+            counts[self.gen_bounded(bound) as usize] += 1;
+        }
+        counts
+    }
+
+    /// Like `fill_bytes`, but writes each word in big-endian order.
+    ///
+    /// Words are drawn exactly as in `fill_bytes`; only the byte order within each word
+    /// differs. A trailing partial word takes the most significant bytes of its draw.
+    pub fn fill_bytes_be(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(4);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u32().to_be_bytes());
+        }
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let n = tail.len();
+            tail.copy_from_slice(&self.next_u32().to_be_bytes()[..n]);
+        }
+    }
+
+    /// Returns a perfectly uniform value in `[0, bound)`, using rejection sampling.
+    ///
+    /// This is Lemire's multiply-shift method with its rejection step: draws that would fall
+    /// into the slightly overrepresented part of the range are thrown away and redrawn. The
+    /// result is exactly uniform, but the number of words consumed depends on the bound and the
+    /// draws, so positions after this call are not predictable. Prefer
+    /// [`gen_bounded`](Self::gen_bounded) when position stability matters more than the last
+    /// `bound / 2^32` of bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    pub fn gen_range_unbiased(&mut self, bound: u32) -> u32 {
+        assert!(bound > 0, "bound must be nonzero");
+
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = u64::from(self.next_u32()) * u64::from(bound);
+            if product as u32 >= threshold {
+                return (product >> 32) as u32;
+            }
+        }
+    }
+
+    /// Returns the total of `count` rolls of a die numbered `1..=sides`.
+    ///
+    /// Each die consumes exactly one word through [`gen_bounded`](Self::gen_bounded), so the
+    /// result lies in `count..=count * sides` and `count` words are always consumed. Rolling zero
+    /// dice returns 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sides` is zero or the total overflows a `u32`.
+    pub fn roll(&mut self, count: u32, sides: u32) -> u32 {
+        assert!(sides > 0, "a die must have at least one side");
+        (0..count).fold(0u32, |total, _| {
+            total
+                .checked_add(self.gen_bounded(sides) + 1)
+                .expect("dice total overflows u32")
+        })
+    }
+
+    /// Fills `dest` with successive `next_u32` values.
+    pub fn fill_u32_slice(&mut self, dest: &mut [u32]) {
+        for slot in dest {
+            *slot = self.next_u32();
+        }
+    }
+
+    /// Fills `dest` with values in `[0, bound)`, one [`gen_bounded`](Self::gen_bounded) draw per
+    /// slot.
+    ///
+    /// There is no rejection, so the position advances by exactly `dest.len()` and each slot
+    /// shares `gen_bounded`'s tiny bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero, even when `dest` is empty.
+    pub fn fill_range(&mut self, dest: &mut [u32], bound: u32) {
+        assert!(bound > 0, "bound must be nonzero");
+        for slot in dest {
+            *slot = self.gen_bounded(bound);
+        }
+    }
+
+    /// Fills `dest` with successive `next_u64` values, advancing the position by `2 * dest.len()`.
+    ///
+    /// Each element is two words concatenated little-endian, the first draw supplying the low
+    /// half, exactly as [`next_u64_via_u32`] builds it.
+    pub fn fill_u64_slice(&mut self, dest: &mut [u64]) {
+        for slot in dest {
+            *slot = self.next_u64();
+        }
+    }
+
+    /// Returns an array of `N` successive `next_u32` values.
+    pub fn gen_array<const N: usize>(&mut self) -> [u32; N] {
+        core::array::from_fn(|_| self.next_u32())
+    }
+
+    /// Returns the 16 bytes of a random (version 4) UUID, consuming four words.
+    ///
+    /// The bytes are filled as by `fill_bytes`, then the version nibble is set to 4 and the
+    /// variant bits to `10` as RFC 4122 requires, leaving 122 random bits. The result is fully
+    /// determined by the position and seed, which makes it handy for reproducible fixtures, but
+    /// it should not be used where UUIDs must be unguessable.
+    pub fn gen_uuid(&mut self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        self.fill_bytes(&mut bytes);
+        bytes[6] = (bytes[6] & 0x0F) | 0x40;
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+        bytes
+    }
+
+    /// Fills `dest` with successive `next_f32` values, each in `[0, 1)`.
+    pub fn fill_f32_slice(&mut self, dest: &mut [f32]) {
+        for slot in dest {
+            *slot = self.next_f32();
+        }
+    }
+
+    /// Calls `f` until it returns `Some`, and returns that value.
+    ///
+    /// This is the usual rejection-sampling loop: `f` draws what it needs from the rng and
+    /// returns `None` to reject. Each attempt advances the position by however much `f` draws,
+    /// so the result is still determined by the starting position and seed. If `f` can reject
+    /// forever, this loops forever; see [`retry_until_bounded`](Self::retry_until_bounded).
+    pub fn retry_until<T, F: FnMut(&mut Self) -> Option<T>>(&mut self, mut f: F) -> T {
+        loop {
+            if let Some(value) = f(self) {
+                return value;
+            }
+        }
+    }
+
+    /// Like [`retry_until`](Self::retry_until), but gives up and returns `None` after
+    /// `max_attempts` rejections.
+    pub fn retry_until_bounded<T, F: FnMut(&mut Self) -> Option<T>>(
+        &mut self,
+        max_attempts: usize,
+        mut f: F,
+    ) -> Option<T> {
+        (0..max_attempts).find_map(|_| f(self))
+    }
+
+    /// Returns a closure that draws the next `u32` each time it is called.
+    ///
+    /// The closure borrows this rng, so the position advances with every call; this plugs
+    /// directly into APIs such as `core::iter::repeat_with`.
+    pub fn as_fn_mut(&mut self) -> impl FnMut() -> u32 + '_ {
+        move || self.next_u32()
+    }
+
+    /// Converts the rng into an endless iterator over its `u32` output.
+    pub fn iter_u32(self) -> SquirrelIter {
+        SquirrelIter::new(self)
+    }
+
+    /// Returns an iterator over the values at each position in `positions` for `seed`.
+    pub fn range(seed: u32, positions: Range<u32>) -> SquirrelRange {
+        SquirrelRange::new(seed, positions)
+    }
+
+    /// Splits `positions` into `num_chunks` contiguous pieces, yielding each piece's start and an
+    /// rng positioned there.
+    ///
+    /// Piece sizes differ by at most one, and each piece ends where the next one starts (the last
+    /// ends at `positions.end`). A worker that draws a piece's worth of values from its rng
+    /// reproduces exactly that slice of the serial stream. When there are more chunks than
+    /// positions, some pieces are empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_chunks` is zero.
+    pub fn chunks(
+        seed: u32,
+        positions: Range<u32>,
+        num_chunks: usize,
+    ) -> impl Iterator<Item = (u32, SquirrelRng)> {
+        assert!(num_chunks > 0, "cannot split into zero chunks");
+        let len = u64::from(positions.end.saturating_sub(positions.start));
+        let count = num_chunks as u64;
+        (0..count).map(move |i| {
+            let start = positions.start + (i * len / count) as u32;
+            (start, Self::from_state(start, seed))
+        })
+    }
+
+    /// Returns the position in `positions` whose value for `seed` is smallest, or `None` if the
+    /// range is empty.
+    ///
+    /// The scan is O(n) and touches no rng state. On a tie the lowest position wins.
+    pub fn argmin(seed: u32, positions: Range<u32>) -> Option<u32> {
+        positions.min_by_key(|&position| squirrel3(position, seed))
+    }
+
+    /// Returns the position in `positions` whose value for `seed` is largest, or `None` if the
+    /// range is empty.
+    ///
+    /// The scan is O(n) and touches no rng state. On a tie the lowest position wins.
+    pub fn argmax(seed: u32, positions: Range<u32>) -> Option<u32> {
+        positions
+            .rev()
+            .max_by_key(|&position| squirrel3(position, seed))
+    }
+
+    /// Fills `dest` with the bytes starting at `byte_offset` of the byte stream for `seed`.
+    ///
+    /// The stream is what `fill_bytes` produces from position 0: each word contributes its four
+    /// little-endian bytes, so byte `i` comes from position `i / 4`. Offsets need not be word
+    /// aligned, and filling consecutive chunks at consecutive offsets gives exactly the bytes of
+    /// one large `fill_bytes`. The stream repeats after 2^34 bytes, when the position wraps.
+    pub fn fill_bytes_at(seed: u32, byte_offset: u64, dest: &mut [u8]) {
+        let mut position = (byte_offset / 4) as u32;
+        let mut skip = (byte_offset % 4) as usize;
+        let mut dest = dest;
+        while !dest.is_empty() {
+            let bytes = squirrel3(position, seed).to_le_bytes();
+            let n = (4 - skip).min(dest.len());
+            let (head, rest) = dest.split_at_mut(n);
+            head.copy_from_slice(&bytes[skip..skip + n]);
+            dest = rest;
+            skip = 0;
+            position = position.wrapping_add(1);
+        }
+    }
+}
+
+/// Formats the state compactly in hex, e.g. `SquirrelRng(seed=0x0000002A, pos=0x00000010)`.
+impl fmt::Display for SquirrelRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SquirrelRng(seed={:#010X}, pos={:#010X})",
+            self.seed, self.position
+        )
+    }
+}
+
+/// Clears both the seed and the position.
+///
+/// `ZeroizeOnDrop` is not offered: `SquirrelRng` is `Copy`, so it cannot implement `Drop`, and
+/// copies made along the way would not be cleared anyway.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SquirrelRng {
+    fn zeroize(&mut self) {
+        self.position.zeroize();
+        self.seed.zeroize();
+    }
 }
 
 #[cfg(feature = "std")]
@@ -54,95 +1022,270 @@ impl From<OsRng> for SquirrelRng {
     }
 }
 
-impl RngCore for SquirrelRng {
-    #[inline]
-    fn next_u32(&mut self) -> u32 {
-        let result = squirrel3(self.position, self.seed);
-        self.position = self.position.wrapping_add(1);
-        result
+/// Lets any `rand` rng mint a child with `rng.gen::<SquirrelRng>()`.
+///
+/// The child is seeded from one `next_u32` of the source and starts at position 0, exactly as
+/// [`SquirrelRng::seed_from_mut`] would build it.
+impl Distribution<SquirrelRng> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SquirrelRng {
+        SquirrelRng::seed_from_mut(rng)
     }
+}
 
-    #[inline]
-    fn next_u64(&mut self) -> u64 {
-        next_u64_via_u32(self)
+impl From<u32> for SquirrelRng {
+    fn from(seed: u32) -> Self {
+        Self::with_seed(seed)
     }
+}
 
-    #[inline]
-    fn fill_bytes(&mut self, dest: &mut [u8]) {
-        fill_bytes_via_next(self, dest);
-    }
+/// Maps the top 24 bits of `value` to `[0, 1)`.
+#[inline]
+const fn u32_to_unit_f32(value: u32) -> f32 {
+    (value >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+}
 
-    #[inline]
-    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
-        self.fill_bytes(dest);
-        Ok(())
-    }
+macro_rules! next_down {
+    ($name:ident, $float:ty) => {
+        /// Returns the largest float less than `x`, for finite `x`.
+        pub(crate) fn $name(x: $float) -> $float {
+            let bits = x.to_bits();
+            if x == 0.0 {
+                -<$float>::from_bits(1)
+            } else if x > 0.0 {
+                <$float>::from_bits(bits - 1)
+            } else {
+                <$float>::from_bits(bits + 1)
+            }
+        }
+    };
 }
 
-impl SeedableRng for SquirrelRng {
-    type Seed = [u8; 4];
+next_down!(next_down_f32, f32);
+next_down!(next_down_f64, f64);
 
-    fn from_seed(seed: Self::Seed) -> Self {
-        Self::with_seed(u32::from_le_bytes(seed))
-    }
-}
+/// The noise constants used by [`squirrel3`].
+pub const SQUIRREL3_NOISE: [u32; 3] = [0x68E31DA4, 0xB5297A4D, 0x1B56C4E9];
 
+/// Returns the noise value at `position` for `seed`.
+///
+/// Note that the first default noise constant is a multiple of four, so positions that differ by
+/// a multiple of 2^30 produce the same value. This quirk comes from the original algorithm and is
+/// preserved for the sake of stability.
 #[inline]
-pub fn squirrel3(position: u32, seed: u32) -> u32 {
-    const BIT_NOISE1: u32 = 0x68E31DA4;
-    const BIT_NOISE2: u32 = 0xB5297A4D;
-    const BIT_NOISE3: u32 = 0x1B56C4E9;
+pub const fn squirrel3(position: u32, seed: u32) -> u32 {
+    squirrel3_with_constants(position, seed, SQUIRREL3_NOISE)
+}
 
+/// Evaluates the squirrel3 mixing function with caller-supplied noise constants.
+///
+/// Different constant triples give a family of unrelated hash functions. The first and third
+/// constants are multipliers and should be odd: an odd multiplier is a bijection on `u32`, while
+/// an even one throws away high bits of its input and weakens the hash (see the note on
+/// [`squirrel3`], whose first constant is even). The second constant is only added, so any value
+/// works there.
+#[inline]
+pub const fn squirrel3_with_constants(position: u32, seed: u32, noise: [u32; 3]) -> u32 {
     let mut mangled = position;
-    mangled = mangled.wrapping_mul(BIT_NOISE1);
+    mangled = mangled.wrapping_mul(noise[0]);
     mangled = mangled.wrapping_add(seed);
     mangled ^= mangled >> 8;
-    mangled = mangled.wrapping_add(BIT_NOISE2);
+    mangled = mangled.wrapping_add(noise[1]);
     mangled ^= mangled << 8;
-    mangled = mangled.wrapping_mul(BIT_NOISE3);
+    mangled = mangled.wrapping_mul(noise[2]);
     mangled ^= mangled >> 8;
     mangled
 }
 
-// These two implementations are taken directly from the rand library.
+/// Large primes used to fold additional coordinates into a noise position.
+const PRIME1: u32 = 198491317;
+const PRIME2: u32 = 6542989;
 
-/// Implement `next_u64` via `next_u32`, little-endian order.
-pub fn next_u64_via_u32<R: RngCore + ?Sized>(rng: &mut R) -> u64 {
-    // Use LE; we explicitly generate one value before the next.
-    let x = u64::from(rng.next_u32());
-    let y = u64::from(rng.next_u32());
-    (y << 32) | x
+/// Returns the noise value at `(x, y)`.
+///
+/// Negative coordinates are fine; they simply wrap around when converted to `u32`.
+#[inline]
+pub const fn squirrel3_2d(x: i32, y: i32, seed: u32) -> u32 {
+    let position = (x as u32).wrapping_add(PRIME1.wrapping_mul(y as u32));
+    squirrel3(position, seed)
 }
 
-/// Implement `fill_bytes` via `next_u64` and `next_u32`, little-endian order.
+/// Returns the noise value at `(x, y)`, hashing each coordinate in its own round.
 ///
-/// The fastest way to fill a slice is usually to work as long as possible with
-/// integers. That is why this method mostly uses `next_u64`, and only when
-/// there are 4 or less bytes remaining at the end of the slice it uses
-/// `next_u32` once.
-fn fill_bytes_via_next<R: RngCore + ?Sized>(rng: &mut R, dest: &mut [u8]) {
-    let mut left = dest;
-    while left.len() >= 8 {
-        let (l, r) = { left }.split_at_mut(8);
-        left = r;
-        let chunk: [u8; 8] = rng.next_u64().to_le_bytes();
-        l.copy_from_slice(&chunk);
-    }
-    let n = left.len();
-    if n > 4 {
-        let chunk: [u8; 8] = rng.next_u64().to_le_bytes();
-        left.copy_from_slice(&chunk[..n]);
-    } else if n > 0 {
+/// `x` is hashed with [`squirrel3`] first, and the result becomes the seed for hashing `y`. That
+/// costs two full `squirrel3` evaluations where [`squirrel3_2d`] needs one, but it avoids folding
+/// the coordinates into a single position, so `squirrel3_2d`'s diagonal collisions, where
+/// `(x, y)` and `(x + PRIME, y - 1)` always hash alike for its internal prime, go away. It also
+/// decorrelates neighbors much better than flattening coordinates by hand into a position such
+/// as `x + (y << 24)`, whose high bits squirrel3 mixes poorly.
+///
+/// Both coordinates still pass through squirrel3's position, which ignores its top two bits, so
+/// the output repeats every 2^30 along each axis: `(x, y)`, `(x + 2^30, y)`, and `(x, y + 2^30)`
+/// all give the same value.
+#[inline]
+pub const fn squirrel3_2d_mixed(x: i32, y: i32, seed: u32) -> u32 {
+    squirrel3(y as u32, squirrel3(x as u32, seed))
+}
+
+/// Returns the noise value at `(x, y, z)`.
+///
+/// Negative coordinates are fine; they simply wrap around when converted to `u32`.
+#[inline]
+pub const fn squirrel3_3d(x: i32, y: i32, z: i32, seed: u32) -> u32 {
+    let position = (x as u32)
+        .wrapping_add(PRIME1.wrapping_mul(y as u32))
+        .wrapping_add(PRIME2.wrapping_mul(z as u32));
+    squirrel3(position, seed)
+}
+
+/// Returns the noise value at `(x, y)` on a grid that repeats every `period_x` by `period_y`
+/// cells.
+///
+/// Both coordinates are reduced (Euclidean, so negative coordinates work too) modulo their
+/// period before hashing with [`squirrel3_2d`], so `x` and `x + period_x` always sample the same
+/// value. This is what a texture needs to tile without seams.
+///
+/// # Panics
+///
+/// Panics if either period is zero.
+#[inline]
+pub const fn tileable_2d(x: i32, y: i32, seed: u32, period_x: u32, period_y: u32) -> u32 {
+    assert!(period_x > 0 && period_y > 0, "tile periods must be nonzero");
+    let x = (x as i64).rem_euclid(period_x as i64);
+    let y = (y as i64).rem_euclid(period_y as i64);
+    squirrel3_2d(x as i32, y as i32, seed)
+}
+
+/// Folds several values into a single well-mixed seed.
+///
+/// Each part is mixed into a running result, starting from zero, with the same step
+/// [`SquirrelHasher`] uses: the part enters [`squirrel3`] on the seed side, so every bit of it
+/// counts, and the step is a bijection of the running result. Order matters: permuting the
+/// parts gives a different seed.
+pub const fn combine_seeds(parts: &[u32]) -> u32 {
+    let mut seed = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        seed = hash::fold_word(seed, parts[i]);
+        i += 1;
+    }
+    seed
+}
+
+// These two implementations are taken directly from the rand library.
+
+/// Implement `next_u64` via `next_u32`, little-endian order.
+pub fn next_u64_via_u32<R: RngCore + ?Sized>(rng: &mut R) -> u64 {
+    next_u64_via(|| rng.next_u32())
+}
+
+/// [`next_u64_via_u32`] for a generator given as a closure.
+#[inline]
+fn next_u64_via(mut next_u32: impl FnMut() -> u32) -> u64 {
+    // Use LE; we explicitly generate one value before the next.
+    let x = u64::from(next_u32());
+    let y = u64::from(next_u32());
+    (y << 32) | x
+}
+
+/// Implement `fill_bytes` via `next_u64` and `next_u32`, little-endian order.
+///
+/// The fastest way to fill a slice is usually to work as long as possible with
+/// integers. That is why this method mostly uses `next_u64`, and only when
+/// there are 4 or less bytes remaining at the end of the slice it uses
+/// `next_u32` once.
+///
+/// Other generators can use this to fill bytes exactly the way the rngs in this crate do:
+///
+/// ```
+/// use rand::RngCore;
+/// use squirrel_rng::{fill_bytes_via_next, next_u64_via_u32};
+///
+/// struct Counter(u32);
+///
+/// impl RngCore for Counter {
+///     fn next_u32(&mut self) -> u32 {
+///         self.0 += 1;
+///         self.0
+///     }
+///
+///     fn next_u64(&mut self) -> u64 {
+///         next_u64_via_u32(self)
+///     }
+///
+///     fn fill_bytes(&mut self, dest: &mut [u8]) {
+///         fill_bytes_via_next(self, dest);
+///     }
+///
+///     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+///         self.fill_bytes(dest);
+///         Ok(())
+///     }
+/// }
+///
+/// let mut bytes = [0; 6];
+/// Counter(0).fill_bytes(&mut bytes);
+/// assert_eq!(bytes, [1, 0, 0, 0, 2, 0]);
+/// ```
+pub fn fill_bytes_via_next<R: RngCore + ?Sized>(rng: &mut R, dest: &mut [u8]) {
+    let mut left = dest;
+    while left.len() >= 8 {
+        let (l, r) = { left }.split_at_mut(8);
+        left = r;
+        let chunk: [u8; 8] = rng.next_u64().to_le_bytes();
+        l.copy_from_slice(&chunk);
+    }
+    let n = left.len();
+    if n > 4 {
+        let chunk: [u8; 8] = rng.next_u64().to_le_bytes();
+        left.copy_from_slice(&chunk[..n]);
+    } else if n > 0 {
         let chunk: [u8; 4] = rng.next_u32().to_le_bytes();
         left.copy_from_slice(&chunk[..n]);
     }
 }
 
+/// Implement `fill_bytes` via `next_u32` alone, little-endian order.
+///
+/// For generators whose native width is 32 bits, this skips the work of stitching pairs of
+/// words into a `u64` only to split them apart again. The output is byte-for-byte identical to
+/// `fill_bytes_via_next` for such generators.
+fn fill_bytes_via_u32(dest: &mut [u8], mut next_u32: impl FnMut() -> u32) {
+    let mut chunks = dest.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        chunk.copy_from_slice(&next_u32().to_le_bytes());
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+        let n = tail.len();
+        tail.copy_from_slice(&next_u32().to_le_bytes()[..n]);
+    }
+}
+
+/// Implement `fill_bytes` via `next_u64` alone, little-endian order.
+///
+/// For generators whose `next_u32` is the low half of `next_u64`, the output is byte-for-byte
+/// identical to `fill_bytes_via_next`.
+fn fill_bytes_via_u64(dest: &mut [u8], mut next_u64: impl FnMut() -> u64) {
+    let mut chunks = dest.chunks_exact_mut(8);
+    for chunk in &mut chunks {
+        chunk.copy_from_slice(&next_u64().to_le_bytes());
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+        let n = tail.len();
+        tail.copy_from_slice(&next_u64().to_le_bytes()[..n]);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use rand::RngCore;
-
-    use crate::SquirrelRng;
+    #[cfg(feature = "rand_06")]
+    use crate::fill_bytes_via_next;
+    use crate::{
+        combine_seeds, next_down_f32, next_down_f64, squirrel3, squirrel3_2d, squirrel3_2d_mixed,
+        squirrel3_3d, squirrel3_with_constants, tileable_2d, OverflowPolicy, SquirrelRng,
+        SquirrelRng64, PRIME1, SQUIRREL3_NOISE,
+    };
 
     #[test]
     fn copy_with_position_does_not_modify_original() {
@@ -154,4 +1297,1339 @@ mod tests {
         assert_ne!(a.next_u32(), second_value);
         assert_eq!(a.next_u32(), second_value);
     }
+
+    #[test]
+    fn sample_at_does_not_advance_position() {
+        let mut rng = SquirrelRng::with_seed(3);
+
+        assert_eq!(rng.sample_at(1), rng.sample_at(1));
+        assert_eq!(rng.sample_at(0), rng.next_u32());
+        assert_eq!(rng.sample_at(1), rng.next_u32());
+    }
+
+    #[test]
+    fn noise_2d_is_not_symmetric() {
+        assert_ne!(squirrel3_2d(1, 2, 3), squirrel3_2d(2, 1, 3));
+        assert_ne!(squirrel3_2d(-5, 7, 3), squirrel3_2d(7, -5, 3));
+    }
+
+    #[test]
+    fn tileable_noise_repeats() {
+        for y in -20..20 {
+            assert_eq!(tileable_2d(0, y, 3, 16, 9), tileable_2d(16, y, 3, 16, 9));
+            assert_eq!(tileable_2d(-1, y, 3, 16, 9), tileable_2d(15, y, 3, 16, 9));
+            assert_eq!(tileable_2d(y, 2, 3, 16, 9), tileable_2d(y, 2 - 9, 3, 16, 9));
+        }
+
+        assert_eq!(tileable_2d(5, 4, 3, 16, 9), squirrel3_2d(5, 4, 3));
+    }
+
+    #[test]
+    fn mixed_2d_decorrelates_neighbors() {
+        // Mean number of bits that differ between each cell and its neighbor below.
+        fn vertical_flips(hash: impl Fn(i32, i32) -> u32) -> f64 {
+            let mut total = 0;
+            for y in 0..100 {
+                for x in 0..100 {
+                    total += (hash(x, y) ^ hash(x, y + 1)).count_ones();
+                }
+            }
+            f64::from(total) / 10_000.0
+        }
+
+        let flattened =
+            vertical_flips(|x, y| squirrel3((x as u32).wrapping_add((y as u32) << 24), 3));
+        let mixed = vertical_flips(|x, y| squirrel3_2d_mixed(x, y, 3));
+        assert!(flattened < 12.0, "{}", flattened);
+        assert!((15.8..16.2).contains(&mixed), "{}", mixed);
+
+        let prime = PRIME1 as i32;
+        assert_eq!(squirrel3_2d(5, 7, 3), squirrel3_2d(5 + prime, 6, 3));
+        assert_ne!(
+            squirrel3_2d_mixed(5, 7, 3),
+            squirrel3_2d_mixed(5 + prime, 6, 3)
+        );
+        assert_ne!(squirrel3_2d_mixed(1, 2, 3), squirrel3_2d_mixed(2, 1, 3));
+
+        // Each axis repeats every 2^30, as documented.
+        for high in [1 << 30, i32::MIN, -(1 << 30)] {
+            assert_eq!(
+                squirrel3_2d_mixed(5, 7, 3),
+                squirrel3_2d_mixed(5 + high, 7, 3)
+            );
+            assert_eq!(
+                squirrel3_2d_mixed(5, 7, 3),
+                squirrel3_2d_mixed(5, 7 + high, 3)
+            );
+        }
+    }
+
+    #[test]
+    fn noise_3d_axes_are_independent() {
+        let x: Vec<_> = (0..8).map(|i| squirrel3_3d(i, 0, 0, 3)).collect();
+        let y: Vec<_> = (0..8).map(|i| squirrel3_3d(0, i, 0, 3)).collect();
+        let z: Vec<_> = (0..8).map(|i| squirrel3_3d(0, 0, i, 3)).collect();
+
+        // Every run shares the origin, so compare everything after it.
+        assert_ne!(x[1..], y[1..]);
+        assert_ne!(y[1..], z[1..]);
+        assert_ne!(x[1..], z[1..]);
+    }
+
+    #[test]
+    fn set_position_seeks_in_place() {
+        let mut a = SquirrelRng::with_seed(3);
+        let expected = a.with_position(10).next_u32();
+
+        a.next_u32();
+        a.set_position(10);
+
+        assert_eq!(a.position(), 10);
+        assert_eq!(a.next_u32(), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_preserves_stream() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: SquirrelRng = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(rng, restored);
+        assert_eq!(rng.next_u32(), restored.next_u32());
+    }
+
+    #[test]
+    fn jump_moves_relative_to_position() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+
+        let expected = rng.next_u32();
+        rng.jump(-1);
+        assert_eq!(rng.next_u32(), expected);
+
+        rng.jump(5);
+        assert_eq!(rng.position(), 16);
+
+        rng.set_position(0);
+        rng.jump(-1);
+        assert_eq!(rng.position(), u32::MAX);
+
+        rng.jump(1 << 32);
+        assert_eq!(rng.position(), u32::MAX);
+    }
+
+    #[test]
+    fn u64_seed_uses_high_word() {
+        let mut a = SquirrelRng::with_seed_u64(1 << 32 | 7);
+        let mut b = SquirrelRng::with_seed_u64(2 << 32 | 7);
+
+        assert_ne!(a.next_u32(), b.next_u32());
+        assert_eq!(SquirrelRng::with_seed_u64(7), SquirrelRng::with_seed(7));
+    }
+
+    #[test]
+    fn next_f32_is_in_unit_interval() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for _ in 0..100_000 {
+            let value = rng.next_f32();
+            assert!((0.0..1.0).contains(&value));
+        }
+
+        // The largest possible draw must still stay below 1.0.
+        assert!(((u32::MAX >> 8) as f32 * (1.0 / (1u32 << 24) as f32)) < 1.0);
+    }
+
+    #[test]
+    fn next_f64_is_in_unit_interval() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for _ in 0..100_000 {
+            let value = rng.next_f64();
+            assert!((0.0..1.0).contains(&value));
+        }
+        assert_eq!(rng.position(), 200_000);
+    }
+
+    #[test]
+    fn next_f64_is_deterministic() {
+        let mut a = SquirrelRng::with_seed(3).with_position(7);
+        let mut b = SquirrelRng::with_seed(3).with_position(7);
+        assert_eq!(a.next_f64().to_bits(), b.next_f64().to_bits());
+    }
+
+    #[test]
+    fn from_state_restores_exact_state() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        let mut restored = SquirrelRng::from_state(rng.position(), rng.seed());
+
+        assert_eq!(rng, restored);
+        assert_eq!(rng.next_u32(), restored.next_u32());
+    }
+
+    #[cfg(feature = "rand_06")]
+    #[test]
+    fn fill_bytes_matches_u64_based_fill() {
+        for len in 0..=33 {
+            let mut a = SquirrelRng::with_seed(3);
+            let mut b = a;
+            let mut expected = [0u8; 33];
+            let mut actual = [0u8; 33];
+
+            fill_bytes_via_next(&mut a, &mut expected[..len]);
+            b.fill_bytes(&mut actual[..len]);
+
+            assert_eq!(expected, actual, "length {}", len);
+            assert_eq!(a, b, "length {}", len);
+        }
+    }
+
+    #[cfg(feature = "rand_06")]
+    #[test]
+    fn fill_bytes_partial_word_tails() {
+        // Lengths 5 to 7 take the branch of `fill_bytes_via_next` that draws a whole `u64` for
+        // a partial tail. Both fill paths must agree with a naive byte-at-a-time reference: one
+        // full little-endian word, then the low bytes of the next.
+        for len in 5..=7 {
+            let naive: Vec<u8> = (0..2)
+                .flat_map(|position| squirrel3(position, 3).to_le_bytes())
+                .take(len)
+                .collect();
+
+            let mut a = SquirrelRng::with_seed(3);
+            let mut b = a;
+            let mut via_u32 = [0u8; 7];
+            let mut via_next = [0u8; 7];
+            a.fill_bytes(&mut via_u32[..len]);
+            fill_bytes_via_next(&mut b, &mut via_next[..len]);
+
+            assert_eq!(via_u32[..len], naive[..], "length {}", len);
+            assert_eq!(via_next[..len], naive[..], "length {}", len);
+            assert_eq!(a.position(), 2);
+            assert_eq!(b.position(), 2);
+        }
+    }
+
+    // Evaluated at compile time; a mismatch fails the build rather than the test run.
+    const _: () = assert!(squirrel3(0, 0) == 0xB042BB5C);
+
+    #[test]
+    fn const_constructors() {
+        const RNG: SquirrelRng = SquirrelRng::with_seed(3).with_position(7);
+        assert_eq!(RNG.position(), 7);
+        assert_eq!(RNG.seed(), 3);
+    }
+
+    #[test]
+    fn reseed_matches_fresh_rng() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        rng.reseed(4);
+
+        assert_eq!(rng, SquirrelRng::with_seed(4));
+        assert_eq!(rng.next_u32(), SquirrelRng::with_seed(4).next_u32());
+    }
+
+    #[test]
+    fn seed_from_bytes_is_fnv1a() {
+        assert_eq!(SquirrelRng::seed_from_bytes(b"").seed(), 0x811C9DC5);
+        assert_eq!(SquirrelRng::seed_from_bytes(b"a").seed(), 0xE40C292C);
+        assert_eq!(SquirrelRng::seed_from_bytes(b"foobar").seed(), 0xBF9CF968);
+        assert_ne!(
+            SquirrelRng::seed_from_bytes(b"overworld"),
+            SquirrelRng::seed_from_bytes(b"underworld")
+        );
+    }
+
+    #[test]
+    fn split_children_differ_and_are_reproducible() {
+        let mut parent = SquirrelRng::with_seed(3);
+        let mut a = parent.split();
+        let mut b = parent.split();
+
+        assert_eq!(a.position(), 0);
+        assert_ne!(a.next_u32(), b.next_u32());
+
+        let mut parent = SquirrelRng::with_seed(3);
+        assert_eq!(parent.split(), a.with_position(0));
+    }
+
+    /// These values are part of the stability contract. Never change them.
+    #[test]
+    fn golden_values() {
+        const GOLDEN: &[(u32, u32, u32)] = &[
+            (0, 0, 0xB042BB5C),
+            (1, 0, 0x81E55042),
+            (0, 1, 0xC73DA8AC),
+            (1, 1, 0x61DF0A39),
+            (u32::MAX, 0, 0x5B4D2E3B),
+            (0, u32::MAX, 0x996BBB5C),
+            (u32::MAX, u32::MAX, 0x3A5E5042),
+            (3, 3, 0x1183291F),
+            (0x8000_0000, 0x1234_5678, 0x96797569),
+            (42, 0xDEADBEEF, 0xE3AEC6CA),
+            (1000, 7, 0x4995B878),
+        ];
+
+        for &(position, seed, expected) in GOLDEN {
+            assert_eq!(squirrel3(position, seed), expected);
+
+            let mut rng = SquirrelRng::with_seed(seed).with_position(position);
+            assert_eq!(rng.next_u32(), expected);
+        }
+
+        let mut rng = SquirrelRng::with_seed(0);
+        assert_eq!(rng.next_u64(), 0x81E55042_B042BB5C);
+
+        let mut bytes = [0; 6];
+        SquirrelRng::with_seed(0).fill_bytes(&mut bytes);
+        assert_eq!(bytes, [0x5C, 0xBB, 0x42, 0xB0, 0x42, 0x50]);
+    }
+
+    #[test]
+    fn byte_output_is_little_endian_everywhere() {
+        // Spelled out byte by byte rather than derived with `to_le_bytes`, so the expected
+        // pattern cannot silently follow the host's byte order.
+        const STREAM: [u8; 8] = [0x5C, 0xBB, 0x42, 0xB0, 0x42, 0x50, 0xE5, 0x81];
+
+        let mut bytes = [0; 8];
+        SquirrelRng::with_seed(0).fill_bytes(&mut bytes);
+        assert_eq!(bytes, STREAM);
+
+        let mut bytes = [0; 5];
+        SquirrelRng::fill_bytes_at(0, 3, &mut bytes);
+        assert_eq!(bytes, STREAM[3..8]);
+
+        let mut bytes = [0; 6];
+        SquirrelRng::with_seed(0).fill_bytes_be(&mut bytes);
+        assert_eq!(bytes, [0xB0, 0x42, 0xBB, 0x5C, 0x81, 0xE5]);
+
+        let state = SquirrelRng::from_state(0x0102_0304, 0x0A0B_0C0D).to_bytes();
+        assert_eq!(state, [0x04, 0x03, 0x02, 0x01, 0x0D, 0x0C, 0x0B, 0x0A]);
+
+        let mut bytes = [0; 8];
+        crate::SquirrelRng64::with_seed(0).fill_bytes(&mut bytes);
+        assert_eq!(bytes, [0x0F, 0xF1, 0x83, 0xAB, 0x3D, 0xDC, 0x62, 0xAF]);
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn from_entropy_seeds_differ() {
+        // Any two seeds collide with probability 2^-32, so require only one pair to differ.
+        let seeds = [(); 3].map(|_| SquirrelRng::from_entropy().seed());
+        assert!(seeds[0] != seeds[1] || seeds[1] != seeds[2]);
+    }
+
+    #[test]
+    fn custom_constants_change_the_hash() {
+        assert_eq!(
+            squirrel3_with_constants(5, 3, SQUIRREL3_NOISE),
+            squirrel3(5, 3)
+        );
+        assert_ne!(
+            squirrel3_with_constants(5, 3, [0x68E31DA5, 0xB5297A4D, 0x1B56C4E9]),
+            squirrel3(5, 3)
+        );
+    }
+
+    #[test]
+    fn default_constants_repeat_every_2_pow_30() {
+        assert_eq!(squirrel3(5, 3), squirrel3(5 + (1 << 30), 3));
+        assert_eq!(squirrel3(5, 3), squirrel3(5 + (1 << 31), 3));
+        assert_ne!(squirrel3(5, 3), squirrel3(5 + (1 << 29), 3));
+    }
+
+    #[test]
+    fn peek_does_not_advance() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let peeked = rng.peek();
+
+        assert_eq!(rng.peek(), peeked);
+        assert_eq!(rng.next_u32(), peeked);
+        assert_ne!(rng.peek(), peeked);
+    }
+
+    #[test]
+    fn byte_round_trip() {
+        let mut rng = SquirrelRng::with_seed(0x0403_0201).with_position(0x0807_0605);
+        let bytes = rng.to_bytes();
+        let mut restored = SquirrelRng::from_bytes(bytes);
+
+        assert_eq!(bytes, [5, 6, 7, 8, 1, 2, 3, 4]);
+        assert_eq!(rng, restored);
+        assert_eq!(rng.next_u32(), restored.next_u32());
+    }
+
+    #[test]
+    fn fill_u32_slice_matches_next_u32() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        let mut values = [0; 16];
+        a.fill_u32_slice(&mut values);
+
+        for value in values {
+            assert_eq!(value, b.next_u32());
+        }
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn fill_f32_slice_is_in_unit_interval() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut values = vec![0.0; 100_000];
+        rng.fill_f32_slice(&mut values);
+
+        assert!(values.iter().all(|value| (0.0..1.0).contains(value)));
+        assert_eq!(values[0], SquirrelRng::with_seed(3).next_f32());
+    }
+
+    #[test]
+    fn next_bool_tracks_probability() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for &probability in &[0.1, 0.5, 0.9] {
+            let hits = (0..100_000).filter(|_| rng.next_bool(probability)).count();
+            let frequency = hits as f32 / 100_000.0;
+            assert!((frequency - probability).abs() < 0.01, "{}", frequency);
+        }
+
+        assert!((0..1000).all(|_| !rng.next_bool(0.0)));
+        assert!((0..1000).all(|_| !rng.next_bool(-1.0)));
+        assert!((0..1000).all(|_| rng.next_bool(1.0)));
+        assert!((0..1000).all(|_| rng.next_bool(2.0)));
+
+        let position = rng.position();
+        rng.next_bool(f32::NAN);
+        assert_eq!(rng.position(), position + 1);
+    }
+
+    #[test]
+    fn gen_bounded_is_position_stable() {
+        let mut a = SquirrelRng::with_seed(3).with_position(10);
+        let mut b = SquirrelRng::with_seed(3).with_position(10);
+
+        assert_eq!(a.gen_bounded(6), b.gen_bounded(6));
+        assert_eq!(a.position(), 11);
+
+        // A different bound at the same position still consumes a single word.
+        a.gen_bounded(u32::MAX);
+        b.gen_bounded(1);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn gen_bounded_is_roughly_uniform() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            counts[rng.gen_bounded(6) as usize] += 1;
+        }
+
+        for count in counts {
+            assert!((9_500..10_500).contains(&count), "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn display_shows_hex_state() {
+        let rng = SquirrelRng::with_seed(0x2A).with_position(0x10);
+        assert_eq!(
+            rng.to_string(),
+            "SquirrelRng(seed=0x0000002A, pos=0x00000010)"
+        );
+    }
+
+    #[test]
+    fn next_u128_is_little_endian() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        let words = [(); 4].map(|_| u128::from(b.next_u32()));
+
+        assert_eq!(
+            a.next_u128(),
+            words[0] | words[1] << 32 | words[2] << 64 | words[3] << 96
+        );
+        assert_eq!(a.position(), 4);
+    }
+
+    #[test]
+    fn fill_bytes_be_reverses_each_word() {
+        let mut le = [0; 10];
+        let mut be = [0; 10];
+        SquirrelRng::with_seed(0).fill_bytes(&mut le);
+        SquirrelRng::with_seed(0).fill_bytes_be(&mut be);
+
+        assert_eq!(&be[..4], [0xB0, 0x42, 0xBB, 0x5C]);
+        for (le, be) in le.chunks(4).zip(be.chunks(4)).take(2) {
+            assert!(le.iter().eq(be.iter().rev()));
+        }
+        assert_eq!(be[8..], squirrel3(2, 0).to_be_bytes()[..2]);
+    }
+
+    #[cfg(feature = "rand_06")]
+    #[test]
+    fn seed_from_borrowed_rng() {
+        let mut master = SquirrelRng::with_seed(3);
+        let a = SquirrelRng::seed_from_mut(&mut master);
+        let b = SquirrelRng::seed_from(&mut master);
+
+        assert_eq!(a.seed(), squirrel3(0, 3));
+        assert_eq!(b.seed(), squirrel3(1, 3));
+        assert_eq!(master.position(), 2);
+    }
+
+    #[test]
+    fn try_next_u32_stops_before_wrapping() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(u32::MAX - 1);
+
+        assert_eq!(rng.try_next_u32(), Some(squirrel3(u32::MAX - 1, 3)));
+        assert_eq!(rng.try_next_u32(), None);
+        assert_eq!(rng.position(), u32::MAX);
+    }
+
+    #[test]
+    fn skip_ahead_matches_with_position() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        rng.skip_ahead(5);
+        assert_eq!(
+            rng.next_u32(),
+            SquirrelRng::with_seed(3).with_position(15).next_u32()
+        );
+
+        rng.skip_ahead(u64::from(u32::MAX) + 2);
+        assert_eq!(rng.position(), 17);
+    }
+
+    #[test]
+    fn nth_looks_ahead() {
+        let rng = SquirrelRng::with_seed(3).with_position(10);
+        let mut clone = rng;
+        let sixth = (0..6).map(|_| clone.next_u32()).last();
+
+        assert_eq!(Some(rng.nth(5)), sixth);
+        assert_eq!(rng.nth(0), rng.peek());
+        assert_eq!(rng.position(), 10);
+    }
+
+    #[test]
+    fn gen_array_matches_next_u32() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        let values: [u32; 6] = a.gen_array();
+
+        assert_eq!(values, [(); 6].map(|_| b.next_u32()));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn gen_range_f32_stays_in_range() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for &(start, end) in &[(-3.5, 2.0), (0.0, 1.0), (1.0, 1.0000001), (-1e30, 1e30)] {
+            for _ in 0..10_000 {
+                let value = rng.gen_range_f32(start..end);
+                assert!(
+                    start <= value && value < end,
+                    "{} in {}..{}",
+                    value,
+                    start,
+                    end
+                );
+            }
+        }
+        assert_eq!(rng.position(), 40_000);
+
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = SquirrelRng::with_seed(3);
+        assert_eq!(a.gen_range_f32(-3.5..2.0), b.gen_range_f32(-3.5..2.0));
+    }
+
+    #[test]
+    fn next_down_steps_toward_negative_infinity() {
+        for &x in &[1.0f32, -1.0, 0.0, -0.0, f32::MIN_POSITIVE, f32::MAX] {
+            let down = next_down_f32(x);
+            assert!(down < x);
+            assert!(down.next_up() == x || x == 0.0);
+        }
+        for &x in &[1.0f64, -1.0, 0.0, -0.0, f64::MIN_POSITIVE, f64::MAX] {
+            let down = next_down_f64(x);
+            assert!(down < x);
+            assert!(down.next_up() == x || x == 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn gen_range_f32_rejects_reversed_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        SquirrelRng::with_seed(3).gen_range_f32(2.0..1.0);
+    }
+
+    #[test]
+    fn next_nonzero_u32_skips_zero() {
+        // This seed was chosen by inverting squirrel3 so that position 0 yields zero.
+        let mut rng = SquirrelRng::with_seed(0x4A9C19AA);
+        assert_eq!(rng.peek(), 0);
+
+        assert_eq!(rng.next_nonzero_u32().get(), 0xB38DCEC6);
+        assert_eq!(rng.position(), 2);
+        assert_eq!(rng.next_nonzero_u32().get(), squirrel3(2, 0x4A9C19AA));
+    }
+
+    #[test]
+    fn small_words_take_high_bits_of_one_draw() {
+        let mut rng = SquirrelRng::with_seed(3);
+
+        assert_eq!(rng.next_u8(), (squirrel3(0, 3) >> 24) as u8);
+        assert_eq!(rng.next_u16(), (squirrel3(1, 3) >> 16) as u16);
+        assert_eq!(rng.position(), 2);
+    }
+
+    #[test]
+    fn combine_seeds_depends_on_order() {
+        let world = 12345;
+        let biome = 7;
+        let chunk = 42;
+
+        assert_eq!(combine_seeds(&[]), 0);
+        assert_eq!(combine_seeds(&[world]), squirrel3(0, world));
+        assert_ne!(
+            combine_seeds(&[world, biome, chunk]),
+            combine_seeds(&[world, chunk, biome])
+        );
+        assert_ne!(
+            combine_seeds(&[world, biome, chunk]),
+            combine_seeds(&[chunk, biome, world])
+        );
+    }
+
+    #[test]
+    fn combine_seeds_uses_high_bits() {
+        for high in [1 << 30, 1 << 31, 3 << 30] {
+            assert_ne!(combine_seeds(&[high]), combine_seeds(&[0]), "{:#X}", high);
+            assert_ne!(
+                combine_seeds(&[7, high | 5]),
+                combine_seeds(&[7, 5]),
+                "{:#X}",
+                high
+            );
+            assert_ne!(
+                combine_seeds(&[high | 5, 7]),
+                combine_seeds(&[5, 7]),
+                "{:#X}",
+                high
+            );
+        }
+    }
+
+    #[test]
+    fn fork_is_idempotent_but_split_is_not() {
+        let mut parent = SquirrelRng::with_seed(3).with_position(10);
+
+        assert_eq!(parent.fork(), parent.fork());
+        assert_eq!(parent.position(), 10);
+
+        let forked = parent.fork();
+        assert_eq!(parent.split(), forked);
+        assert_ne!(parent.split(), forked);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize_clears_state() {
+        use zeroize::Zeroize;
+
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        rng.zeroize();
+
+        assert_eq!(rng, SquirrelRng::from_state(0, 0));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn gen_bytes_matches_fill_bytes() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        let mut expected = [0; 13];
+        b.fill_bytes(&mut expected);
+
+        assert_eq!(a.gen_bytes(13), expected);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn gen_range_unbiased_is_uniform() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut counts = [0u32; 6];
+        for _ in 0..60_000 {
+            counts[rng.gen_range_unbiased(6) as usize] += 1;
+        }
+        for count in counts {
+            assert!((9_500..10_500).contains(&count), "{:?}", counts);
+        }
+
+        // Just under half of all draws are rejected for this bound.
+        let bound = (1 << 31) + 1;
+        let mut rng = SquirrelRng::with_seed(3);
+        let low = (0..10_000)
+            .filter(|_| rng.gen_range_unbiased(bound) < bound / 2)
+            .count();
+        assert!((4_800..5_200).contains(&low), "{}", low);
+        assert!(rng.position() > 15_000);
+    }
+
+    #[test]
+    fn remaining_counts_down_to_wrap() {
+        let mut rng = SquirrelRng::with_seed(3);
+        assert_eq!(rng.remaining(), u32::MAX);
+
+        rng.next_u32();
+        assert_eq!(rng.remaining(), u32::MAX - 1);
+
+        rng.set_position(u32::MAX - 2);
+        assert_eq!(rng.remaining(), 2);
+        while rng.try_next_u32().is_some() {}
+        assert_eq!(rng.remaining(), 0);
+    }
+
+    #[test]
+    fn streams_are_stable_and_distinct() {
+        let mut master = SquirrelRng::with_seed(3);
+        let physics = master.stream(0);
+        master.next_u32();
+
+        assert_eq!(master.stream(0), physics);
+        assert_eq!(physics, SquirrelRng::with_seed(squirrel3(0, 3)));
+
+        let a: Vec<u32> = master.stream(1).iter_u32().take(64).collect();
+        let b: Vec<u32> = master.stream(2).iter_u32().take(64).collect();
+        assert!(a.iter().zip(&b).all(|(x, y)| x != y));
+    }
+
+    #[test]
+    fn from_u32_is_with_seed() {
+        let rng: SquirrelRng = 42.into();
+        assert_eq!(rng, SquirrelRng::with_seed(42));
+        assert_eq!(SquirrelRng::from(42), SquirrelRng::with_seed(42));
+    }
+
+    #[test]
+    fn roll_stays_in_range() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for &(count, sides) in &[(1, 6), (3, 6), (10, 20), (4, 1)] {
+            for _ in 0..1_000 {
+                let total = rng.roll(count, sides);
+                assert!((count..=count * sides).contains(&total), "{}", total);
+            }
+        }
+
+        assert_eq!(rng.roll(0, 6), 0);
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        assert_eq!(a.roll(3, 6), b.roll(3, 6));
+        assert_eq!(a.position(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn roll_rejects_zero_sides() {
+        SquirrelRng::with_seed(3).roll(1, 0);
+    }
+
+    #[test]
+    fn fill_bytes_at_matches_contiguous_fill() {
+        let mut expected = [0; 103];
+        SquirrelRng::with_seed(3).fill_bytes(&mut expected);
+
+        for &split in &[0, 1, 3, 4, 5, 50, 102, 103] {
+            let mut actual = [0; 103];
+            let (head, tail) = actual.split_at_mut(split);
+            SquirrelRng::fill_bytes_at(3, 0, head);
+            SquirrelRng::fill_bytes_at(3, split as u64, tail);
+            assert_eq!(actual, expected, "split at {}", split);
+        }
+
+        let mut chunked = [0; 103];
+        for (i, chunk) in chunked.chunks_mut(7).enumerate() {
+            SquirrelRng::fill_bytes_at(3, i as u64 * 7, chunk);
+        }
+        assert_eq!(chunked, expected);
+    }
+
+    #[test]
+    fn ordering_is_position_then_seed() {
+        let mut states = [
+            SquirrelRng::from_state(1, 0),
+            SquirrelRng::from_state(0, 2),
+            SquirrelRng::from_state(0, 1),
+        ];
+        states.sort();
+
+        assert_eq!(
+            states,
+            [
+                SquirrelRng::from_state(0, 1),
+                SquirrelRng::from_state(0, 2),
+                SquirrelRng::from_state(1, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn gen_range_i32_covers_signed_ranges() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for range in [
+            -10..-5,
+            -3..4,
+            0..1,
+            i32::MIN..i32::MIN + 2,
+            i32::MAX - 1..i32::MAX,
+        ] {
+            for _ in 0..1_000 {
+                assert!(range.contains(&rng.gen_range_i32(range.clone())));
+            }
+        }
+
+        let mut negative = false;
+        let mut positive = false;
+        for _ in 0..1_000 {
+            let value = rng.gen_range_i32(i32::MIN..i32::MAX);
+            negative |= value < 0;
+            positive |= value > 0;
+        }
+        assert!(negative && positive);
+
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        assert_eq!(a.gen_range_i32(-100..100), b.gen_range_i32(-100..100));
+        assert_eq!(a.position(), 1);
+    }
+
+    #[test]
+    fn prev_u32_walks_backward() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        let start = rng;
+        let forward: Vec<u32> = (0..5).map(|_| rng.next_u32()).collect();
+        let backward: Vec<u32> = (0..5).map(|_| rng.prev_u32()).collect();
+
+        assert!(forward.iter().rev().eq(&backward));
+        assert_eq!(rng, start);
+
+        let mut rng = SquirrelRng::with_seed(3);
+        assert_eq!(rng.prev_u32(), squirrel3(u32::MAX, 3));
+        assert_eq!(rng.position(), u32::MAX);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_time_changes_with_the_clock() {
+        let a = SquirrelRng::from_time();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let b = SquirrelRng::from_time();
+
+        assert_eq!(a.position(), 0);
+        assert_ne!(a.seed(), b.seed());
+    }
+
+    #[test]
+    fn gen_uuid_sets_version_and_variant() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for _ in 0..100 {
+            let uuid = rng.gen_uuid();
+            assert_eq!(uuid[6] >> 4, 4);
+            assert_eq!(uuid[8] >> 6, 0b10);
+        }
+        assert_eq!(rng.position(), 400);
+
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        let mut raw = [0; 16];
+        b.fill_bytes(&mut raw);
+        let uuid = a.gen_uuid();
+        assert_eq!(uuid, SquirrelRng::with_seed(3).gen_uuid());
+        assert_eq!(uuid[..6], raw[..6]);
+        assert_eq!(uuid[9..], raw[9..]);
+    }
+
+    #[test]
+    fn state_tuple_round_trips() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        let snapshot = rng.state();
+        let expected = rng.next_u32();
+
+        assert_eq!(snapshot, (10, 3));
+        let mut restored = SquirrelRng::from_state_tuple(snapshot);
+        assert_eq!(restored.next_u32(), expected);
+        assert_eq!(restored, rng);
+    }
+
+    #[test]
+    fn gen_bounded_pow2_matches_gen_bounded() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut counts = [0u32; 16];
+        for _ in 0..160_000 {
+            counts[rng.gen_bounded_pow2(4) as usize] += 1;
+        }
+        for count in counts {
+            assert!((9_500..10_500).contains(&count), "{:?}", counts);
+        }
+
+        for bits in 1..32 {
+            let mut a = SquirrelRng::with_seed(3);
+            let mut b = a;
+            assert_eq!(a.gen_bounded_pow2(bits), b.gen_bounded(1 << bits));
+        }
+
+        let mut a = SquirrelRng::with_seed(3);
+        let b = a;
+        assert_eq!(a.gen_bounded_pow2(0), 0);
+        assert_eq!(a.gen_bounded_pow2(32), b.nth(1));
+        assert_eq!(a.position(), 2);
+    }
+
+    #[test]
+    fn with_different_seed_keeps_position() {
+        let rng = SquirrelRng::with_seed(3).with_position(10);
+        let mut other = rng.with_different_seed(4);
+
+        assert_eq!(other.position(), 10);
+        assert_eq!(other.seed(), 4);
+        assert_ne!(other.next_u32(), rng.peek());
+        assert_eq!(other.position(), 11);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn take_u32_matches_next_u32() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        let values = a.take_u32(5);
+
+        assert_eq!(values.len(), 5);
+        for value in values {
+            assert_eq!(value, b.next_u32());
+        }
+        assert_eq!(a.position(), 5);
+        assert!(a.take_u32(0).is_empty());
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn next_with_position_reports_drawn_position() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(u32::MAX);
+
+        assert_eq!(rng.next_with_position(), (u32::MAX, squirrel3(u32::MAX, 3)));
+        assert_eq!(rng.next_with_position(), (0, squirrel3(0, 3)));
+        assert_eq!(rng.position(), 1);
+    }
+
+    #[test]
+    fn gen_range_inclusive_reaches_both_ends() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut seen = [false; 4];
+        for _ in 0..1_000 {
+            let value = rng.gen_range_inclusive(10..=13);
+            seen[(value - 10) as usize] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+        assert_eq!(rng.gen_range_inclusive(7..=7), 7);
+
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        assert_eq!(a.gen_range_inclusive(0..=u32::MAX), b.next_u32());
+        assert_eq!(
+            a.gen_range_inclusive(u32::MAX - 1..=u32::MAX),
+            u32::MAX - 1 + (b.next_u32() >> 31)
+        );
+        assert_eq!(a.position(), 2);
+    }
+
+    #[test]
+    fn flip_is_fair() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let heads = (0..100_000).filter(|_| rng.flip()).count();
+
+        assert!((49_500..50_500).contains(&heads), "{}", heads);
+        assert_eq!(rng.position(), 100_000);
+        assert_eq!(
+            SquirrelRng::with_seed(3).flip(),
+            SquirrelRng::with_seed(3).peek() >= 1 << 31
+        );
+    }
+
+    #[test]
+    fn fill_u64_slice_matches_next_u64() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        let mut values = [0; 5];
+        a.fill_u64_slice(&mut values);
+
+        for value in values {
+            assert_eq!(value, b.next_u64());
+        }
+        assert_eq!(a.position(), 10);
+        assert_eq!(
+            values[0],
+            u64::from(squirrel3(1, 3)) << 32 | u64::from(squirrel3(0, 3))
+        );
+    }
+
+    #[test]
+    fn same_seed_ignores_position() {
+        let a = SquirrelRng::with_seed(3);
+        let b = a.with_position(10);
+
+        assert!(a.same_seed(&b));
+        assert_ne!(a, b);
+        assert!(!a.same_seed(&SquirrelRng::with_seed(4)));
+    }
+
+    #[test]
+    fn reseed_keep_position_changes_only_the_stream() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        let before = rng.peek();
+        rng.reseed_keep_position(4);
+
+        assert_eq!(rng.position(), 10);
+        assert_eq!(rng.seed(), 4);
+        assert_ne!(rng.peek(), before);
+        assert_eq!(rng.next_u32(), squirrel3(10, 4));
+    }
+
+    #[test]
+    fn as_fn_mut_advances_the_rng() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut expected = rng;
+        let values: Vec<u32> = core::iter::repeat_with(rng.as_fn_mut()).take(4).collect();
+
+        for value in values {
+            assert_eq!(value, expected.next_u32());
+        }
+        assert_eq!(rng, expected);
+    }
+
+    #[test]
+    fn argmin_and_argmax_match_a_manual_scan() {
+        let values: Vec<u32> = (100..200).map(|position| squirrel3(position, 3)).collect();
+        let min = values.iter().min().unwrap();
+        let max = values.iter().max().unwrap();
+
+        let argmin = SquirrelRng::argmin(3, 100..200).unwrap();
+        let argmax = SquirrelRng::argmax(3, 100..200).unwrap();
+        assert_eq!(squirrel3(argmin, 3), *min);
+        assert_eq!(squirrel3(argmax, 3), *max);
+        assert_eq!(
+            argmin,
+            100 + values.iter().position(|v| v == min).unwrap() as u32
+        );
+        assert_eq!(
+            argmax,
+            100 + values.iter().position(|v| v == max).unwrap() as u32
+        );
+
+        assert_eq!(SquirrelRng::argmin(3, 5..5), None);
+        assert_eq!(SquirrelRng::argmax(3, 5..5), None);
+    }
+
+    #[test]
+    fn next_u64_fast_is_a_separate_stream() {
+        let mut rng = SquirrelRng::with_seed(0);
+        assert_eq!(rng.next_u64_fast(), 0xAF62DC3DAB83F10F);
+        assert_eq!(rng.next_u64_fast(), 0x9A9CF4AA0EDBF1E5);
+        assert_eq!(rng.position(), 2);
+
+        let mut rng = SquirrelRng::with_seed(3).with_position(u32::MAX);
+        let mut wide = SquirrelRng64::with_seed(3).with_position(u64::from(u32::MAX));
+        assert_eq!(rng.next_u64_fast(), wide.next_u64());
+        assert_eq!(rng.position(), 0);
+        assert_ne!(
+            SquirrelRng::with_seed(0).next_u64_fast(),
+            SquirrelRng::with_seed(0).next_u64()
+        );
+    }
+
+    #[test]
+    fn chunks_reassemble_the_serial_stream() {
+        let serial: Vec<u32> = SquirrelRng::range(3, 10..110).collect();
+        for num_chunks in [1, 3, 7, 100, 150] {
+            let chunks: Vec<_> = SquirrelRng::chunks(3, 10..110, num_chunks).collect();
+            assert_eq!(chunks.len(), num_chunks);
+
+            let ends = chunks
+                .iter()
+                .skip(1)
+                .map(|&(start, _)| start)
+                .chain(Some(110));
+            let mut stitched = Vec::new();
+            for (&(start, mut rng), end) in chunks.iter().zip(ends) {
+                assert_eq!(rng.position(), start);
+                assert!(end - start <= 100 / num_chunks as u32 + 1);
+                stitched.extend((start..end).map(|_| rng.next_u32()));
+            }
+            assert_eq!(stitched, serial, "{} chunks", num_chunks);
+        }
+    }
+
+    #[cfg(feature = "rand_06")]
+    #[test]
+    fn standard_distribution_mints_children() {
+        use rand::Rng;
+
+        let mut parent = SquirrelRng::with_seed(3);
+        let child: SquirrelRng = parent.gen();
+        assert_eq!(child, SquirrelRng::with_seed(squirrel3(0, 3)));
+        assert_eq!(parent.position(), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn thread_rng_mints_distinct_children() {
+        use rand::Rng;
+
+        // Any two seeds collide with probability 2^-32, so require only one pair to differ.
+        let mut rng = rand::thread_rng();
+        let children = [(); 3].map(|_| rng.gen::<SquirrelRng>());
+        assert!(children[0] != children[1] || children[1] != children[2]);
+        assert!(children.iter().all(|child| child.position() == 0));
+    }
+
+    #[test]
+    fn wrap_policy_wraps_to_zero() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(u32::MAX);
+        assert_eq!(rng.overflow_policy(), OverflowPolicy::Wrap);
+        assert_eq!(rng.next_u32(), squirrel3(u32::MAX, 3));
+        assert_eq!(rng.position(), 0);
+    }
+
+    #[test]
+    fn saturate_policy_repeats_the_last_value() {
+        let mut rng = SquirrelRng::with_seed(3)
+            .with_position(u32::MAX - 1)
+            .with_overflow_policy(OverflowPolicy::Saturate);
+        assert_eq!(rng.next_u32(), squirrel3(u32::MAX - 1, 3));
+        for _ in 0..3 {
+            assert_eq!(rng.next_u32(), squirrel3(u32::MAX, 3));
+            assert_eq!(rng.position(), u32::MAX);
+        }
+        rng.next_u64_fast();
+        assert_eq!(rng.position(), u32::MAX);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn panic_policy_panics_at_the_end() {
+        let mut rng = SquirrelRng::with_seed(3)
+            .with_position(u32::MAX - 1)
+            .with_overflow_policy(OverflowPolicy::Panic);
+        assert_eq!(rng.next_u32(), squirrel3(u32::MAX - 1, 3));
+        rng.next_u32();
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn panic_policy_wraps_in_release() {
+        let mut rng = SquirrelRng::with_seed(3)
+            .with_position(u32::MAX)
+            .with_overflow_policy(OverflowPolicy::Panic);
+        assert_eq!(rng.next_u32(), squirrel3(u32::MAX, 3));
+        assert_eq!(rng.position(), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_omits_the_default_overflow_policy() {
+        let rng = SquirrelRng::with_seed(3).with_position(10);
+        let json = serde_json::to_string(&rng).unwrap();
+        assert_eq!(json, r#"{"position":10,"seed":3}"#);
+        assert_eq!(serde_json::from_str::<SquirrelRng>(&json).unwrap(), rng);
+
+        let saturating = rng.with_overflow_policy(OverflowPolicy::Saturate);
+        let json = serde_json::to_string(&saturating).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SquirrelRng>(&json).unwrap(),
+            saturating
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn histogram_counts_every_sample() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let counts = rng.histogram(10, 100_000);
+
+        assert_eq!(counts.len(), 10);
+        assert_eq!(counts.iter().sum::<usize>(), 100_000);
+        assert_eq!(rng.position(), 100_000);
+        // Each count has a standard deviation of about 95; allow five of them.
+        assert!(
+            counts.iter().all(|&count| count.abs_diff(10_000) < 475),
+            "{:?}",
+            counts
+        );
+    }
+
+    #[test]
+    fn antithetic_pairs_mirror_next_f64() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut plain = rng;
+        for _ in 0..1_000 {
+            let (u, v) = rng.next_f64_antithetic();
+            assert_eq!(u, plain.next_f64());
+            assert!((u + v - 1.0).abs() < 1e-15);
+        }
+        assert_eq!(rng.position(), 2_000);
+    }
+
+    #[test]
+    fn retry_until_stops_at_first_accept() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut reference = rng;
+        let attempts = core::iter::repeat_with(|| reference.next_u32())
+            .position(|x| x % 8 == 0)
+            .unwrap()
+            + 1;
+        assert!(attempts > 1);
+
+        let value = rng.retry_until(|rng| Some(rng.next_u32()).filter(|x| x % 8 == 0));
+        assert_eq!(value, squirrel3(attempts as u32 - 1, 3));
+        assert_eq!(rng.position(), attempts as u32);
+
+        let mut rng = SquirrelRng::with_seed(3);
+        let accept = |rng: &mut SquirrelRng| Some(rng.next_u32()).filter(|x| x % 8 == 0);
+        assert_eq!(rng.retry_until_bounded(attempts - 1, accept), None);
+        assert_eq!(rng.position(), attempts as u32 - 1);
+        rng.set_position(0);
+        assert_eq!(rng.retry_until_bounded(attempts, accept), Some(value));
+    }
+
+    #[test]
+    fn position_after_wraps() {
+        let rng = SquirrelRng::with_seed(3).with_position(u32::MAX - 1);
+        assert_eq!(rng.position_after(0), u32::MAX - 1);
+        assert_eq!(rng.position_after(1), u32::MAX);
+        assert_eq!(rng.position_after(2), 0);
+        assert_eq!(rng.position_after(u32::MAX), u32::MAX - 2);
+
+        let mut skipped = rng;
+        skipped.skip_ahead(5);
+        assert_eq!(rng.position_after(5), skipped.position());
+        assert_eq!(rng.position(), u32::MAX - 1);
+    }
+
+    #[test]
+    fn fill_range_matches_gen_bounded() {
+        for &bound in &[1, 6, 1_000, u32::MAX] {
+            let mut rng = SquirrelRng::with_seed(3);
+            let mut reference = rng;
+            let mut values = [0; 100];
+            rng.fill_range(&mut values, bound);
+
+            assert!(values.iter().all(|&value| value < bound));
+            assert!(values
+                .iter()
+                .all(|&value| value == reference.gen_bounded(bound)));
+            assert_eq!(rng.position(), 100);
+        }
+    }
+
+    #[test]
+    fn probe_matches_squirrel3() {
+        let rng = SquirrelRng::with_seed(3).with_position(10);
+        let positions = [0, 7, 10, u32::MAX];
+        let values = rng.probe(positions);
+
+        for (&position, &value) in positions.iter().zip(&values) {
+            assert_eq!(value, squirrel3(position, 3));
+        }
+        assert_eq!(rng.probe([]), [0u32; 0]);
+        assert_eq!(rng.position(), 10);
+    }
+
+    #[test]
+    fn gen_sign_is_even_and_uses_one_word() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut reference = rng;
+        let mut total = 0;
+        for _ in 0..100_000 {
+            let sign = rng.gen_sign();
+            assert_eq!(sign == -1, reference.flip());
+            total += sign;
+        }
+        assert_eq!(rng.position(), 100_000);
+        // The sum has a standard deviation of about 316.
+        assert!(total.abs() < 1_600, "{}", total);
+
+        let mut rng = SquirrelRng::with_seed(3);
+        assert_eq!(
+            rng.gen_sign_f32(),
+            SquirrelRng::with_seed(3).gen_sign() as f32
+        );
+        assert_eq!(rng.position(), 1);
+    }
+
+    #[test]
+    fn chunk_rngs_are_fixed_and_unrelated() {
+        assert_eq!(
+            SquirrelRng::chunk_rng(3, -4, 9),
+            SquirrelRng::chunk_rng(3, -4, 9)
+        );
+        assert_eq!(SquirrelRng::chunk_rng(3, -4, 9).position(), 0);
+        assert_ne!(
+            SquirrelRng::chunk_rng(3, -4, 9),
+            SquirrelRng::chunk_rng(3, -3, 9)
+        );
+
+        // Correlate the first draw of each chunk with that of its neighbor along either axis.
+        let n = 10_000;
+        for (dx, dy) in [(1, 0), (0, 1)] {
+            let pairs: Vec<(f64, f64)> = (0..n)
+                .map(|i| {
+                    let first = |x, y| SquirrelRng::chunk_rng(3, x, y).next_u32() as f64;
+                    (first(i, -i), first(i + dx, -i + dy))
+                })
+                .collect();
+            let mean_a = pairs.iter().map(|p| p.0).sum::<f64>() / n as f64;
+            let mean_b = pairs.iter().map(|p| p.1).sum::<f64>() / n as f64;
+            let covariance: f64 = pairs.iter().map(|p| (p.0 - mean_a) * (p.1 - mean_b)).sum();
+            let variance_a: f64 = pairs.iter().map(|p| (p.0 - mean_a).powi(2)).sum();
+            let variance_b: f64 = pairs.iter().map(|p| (p.1 - mean_b).powi(2)).sum();
+            let correlation = covariance / (variance_a * variance_b).sqrt();
+
+            // The standard deviation of the estimate is about 0.01.
+            assert!(correlation.abs() < 0.05, "{:?}: {}", (dx, dy), correlation);
+        }
+    }
+
+    #[test]
+    fn balanced_bytes_have_four_bits_set() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut seen = [false; 256];
+        for _ in 0..10_000 {
+            let byte = rng.next_balanced_byte();
+            assert_eq!(byte.count_ones(), 4);
+            seen[byte as usize] = true;
+        }
+        assert_eq!(seen.iter().filter(|&&seen| seen).count(), 70);
+        // About 13,860 words are expected, with a standard deviation of about 75.
+        assert!(
+            (13_500..14_300).contains(&rng.position()),
+            "{}",
+            rng.position()
+        );
+
+        let mut a = SquirrelRng::with_seed(7);
+        let mut b = a;
+        for _ in 0..100 {
+            assert_eq!(a.next_balanced_byte(), b.next_balanced_byte());
+        }
+        assert_eq!(a.position(), b.position());
+    }
+
+    #[test]
+    fn tee_copies_walk_the_same_stream() {
+        let mut rng = SquirrelRng::with_seed(3).with_position(10);
+        let (mut a, mut b) = rng.tee();
+        let from_a: Vec<u32> = (0..10).map(|_| a.next_u32()).collect();
+        let from_b: Vec<u32> = (0..10).map(|_| b.next_u32()).collect();
+
+        assert_eq!(from_a, from_b);
+        assert_eq!(from_a, (0..10).map(|_| rng.next_u32()).collect::<Vec<_>>());
+        assert_eq!(a, rng);
+    }
 }
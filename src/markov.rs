@@ -0,0 +1,88 @@
+//! A small stateful generator built on weighted transitions.
+
+use alloc::vec::Vec;
+
+use crate::SquirrelRng;
+
+/// A discrete Markov chain with integer transition weights.
+///
+/// Row `i` of the matrix holds the weights of moving from state `i` to each state. Each
+/// [`step`](Self::step) consumes exactly one word via
+/// [`next_transition`](SquirrelRng::next_transition), so a walk is reproducible from the rng's
+/// position and seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkovChain {
+    transitions: Vec<Vec<u32>>,
+    state: usize,
+}
+
+impl MarkovChain {
+    /// Creates a chain in state `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square, if any row has no nonzero weight, or if `start` is
+    /// not a valid state.
+    pub fn new(transitions: Vec<Vec<u32>>, start: usize) -> Self {
+        let n = transitions.len();
+        assert!(start < n, "start state is out of range");
+        for row in &transitions {
+            assert_eq!(row.len(), n, "transition matrix must be square");
+            assert!(
+                row.iter().any(|&weight| weight > 0),
+                "every state needs a transition with nonzero weight"
+            );
+        }
+        Self {
+            transitions,
+            state: start,
+        }
+    }
+
+    pub fn state(&self) -> usize {
+        self.state
+    }
+
+    /// Moves to the next state and returns it.
+    pub fn step(&mut self, rng: &mut SquirrelRng) -> usize {
+        self.state = rng.next_transition(&self.transitions[self.state]);
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    use super::MarkovChain;
+
+    #[test]
+    fn two_state_chain_reaches_stationary_frequencies() {
+        // Leaving state 0 has probability 1/4 and leaving state 1 has probability 3/4, so the
+        // chain spends 3/4 of its time in state 0.
+        let mut chain = MarkovChain::new(vec![vec![3, 1], vec![3, 1]], 1);
+        let mut rng = SquirrelRng::with_seed(3);
+        let steps = 100_000;
+        let in_zero = (0..steps).filter(|_| chain.step(&mut rng) == 0).count();
+
+        assert_eq!(rng.position(), steps as u32);
+        let frequency = in_zero as f64 / steps as f64;
+        assert!((frequency - 0.75).abs() < 0.01, "{}", frequency);
+    }
+
+    #[test]
+    fn absorbing_state_stays_put() {
+        let mut chain = MarkovChain::new(vec![vec![1, 1], vec![0, 1]], 0);
+        let mut rng = SquirrelRng::with_seed(3);
+        while chain.step(&mut rng) == 0 {}
+        for _ in 0..100 {
+            assert_eq!(chain.step(&mut rng), 1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_ragged_matrix() {
+        MarkovChain::new(vec![vec![1, 1], vec![1]], 0);
+    }
+}
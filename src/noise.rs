@@ -0,0 +1,209 @@
+//! Smooth, floating-point noise built on top of [`squirrel3_2d`]. These functions need `std` for
+//! float operations such as `floor`.
+
+use crate::{squirrel3, squirrel3_2d};
+
+/// Maps a hash to `[0, 1]`.
+#[inline]
+fn unit(hash: u32) -> f32 {
+    hash as f32 / u32::MAX as f32
+}
+
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Returns smoothly varying value noise in `[0, 1]` at `(x, y)`.
+///
+/// Each integer lattice point gets a value from [`squirrel3_2d`], and points in between are
+/// bilinearly interpolated from the four surrounding lattice values. The result is continuous
+/// but has visible creases along lattice lines.
+pub fn value_noise_2d(x: f32, y: f32, seed: u32) -> f32 {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let tx = x - x0;
+    let ty = y - y0;
+    let (x0, y0) = (x0 as i32, y0 as i32);
+
+    let v00 = unit(squirrel3_2d(x0, y0, seed));
+    let v10 = unit(squirrel3_2d(x0.wrapping_add(1), y0, seed));
+    let v01 = unit(squirrel3_2d(x0, y0.wrapping_add(1), seed));
+    let v11 = unit(squirrel3_2d(x0.wrapping_add(1), y0.wrapping_add(1), seed));
+
+    lerp(lerp(v00, v10, tx), lerp(v01, v11, tx), ty)
+}
+
+/// Returns fractal Brownian motion noise in `[0, 1]` at `(x, y)`.
+///
+/// Sums `octaves` layers of [`value_noise_2d`]. Each octave multiplies the frequency by
+/// `lacunarity` and the amplitude by `gain`, and gets its own seed derived from `seed`. The sum is
+/// divided by the total amplitude, so as long as `gain` is positive the result stays in `[0, 1]`.
+/// Typical values are a lacunarity of 2.0 and a gain of 0.5. Zero octaves yield 0.0.
+pub fn fbm_2d(x: f32, y: f32, seed: u32, octaves: u32, lacunarity: f32, gain: f32) -> f32 {
+    let mut sum = 0.0;
+    let mut total_amplitude = 0.0;
+    let mut frequency = 1.0;
+    let mut amplitude = 1.0;
+
+    for octave in 0..octaves {
+        let octave_seed = squirrel3(octave, seed);
+        sum += amplitude * value_noise_2d(x * frequency, y * frequency, octave_seed);
+        total_amplitude += amplitude;
+        frequency *= lacunarity;
+        amplitude *= gain;
+    }
+
+    if total_amplitude > 0.0 {
+        sum / total_amplitude
+    } else {
+        0.0
+    }
+}
+
+/// The gradients used by [`perlin_2d`]: the four axis directions and the four diagonals.
+const GRADIENTS: [[f32; 2]; 8] = [
+    [1.0, 0.0],
+    [-1.0, 0.0],
+    [0.0, 1.0],
+    [0.0, -1.0],
+    [1.0, 1.0],
+    [-1.0, 1.0],
+    [1.0, -1.0],
+    [-1.0, -1.0],
+];
+
+/// Perlin's quintic fade curve, `6t^5 - 15t^4 + 10t^3`.
+#[inline]
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Returns the dot product of the lattice point's gradient with the offset `(dx, dy)` to it.
+#[inline]
+fn gradient_dot(x: i32, y: i32, seed: u32, dx: f32, dy: f32) -> f32 {
+    let [gx, gy] = GRADIENTS[(squirrel3_2d(x, y, seed) >> 29) as usize];
+    gx * dx + gy * dy
+}
+
+/// Returns smooth gradient (Perlin) noise in `[-1, 1]` at `(x, y)`.
+///
+/// Each integer lattice point picks one of eight gradients (the axis directions and the
+/// unnormalized diagonals `(±1, ±1)`) from the top bits of [`squirrel3_2d`]. The dot products of
+/// those gradients with the offsets to `(x, y)` are blended with Perlin's quintic fade curve, so
+/// unlike [`value_noise_2d`] the result has no creases along lattice lines. Noise is zero at every
+/// lattice point, and in practice the output rarely gets close to ±1.
+pub fn perlin_2d(x: f32, y: f32, seed: u32) -> f32 {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let tx = x - x0;
+    let ty = y - y0;
+    let (x0, y0) = (x0 as i32, y0 as i32);
+    let (x1, y1) = (x0.wrapping_add(1), y0.wrapping_add(1));
+
+    let n00 = gradient_dot(x0, y0, seed, tx, ty);
+    let n10 = gradient_dot(x1, y0, seed, tx - 1.0, ty);
+    let n01 = gradient_dot(x0, y1, seed, tx, ty - 1.0);
+    let n11 = gradient_dot(x1, y1, seed, tx - 1.0, ty - 1.0);
+
+    let (u, v) = (fade(tx), fade(ty));
+    lerp(lerp(n00, n10, u), lerp(n01, n11, u), v).clamp(-1.0, 1.0)
+}
+
+/// Returns Worley (cellular) noise in `[0, 1]` at `(x, y)`.
+///
+/// Every integer grid cell holds one feature point, placed within the cell by hashing the cell's
+/// coordinates with [`squirrel3_2d`]. The result is the distance from `(x, y)` to the nearest
+/// feature point among the 3×3 block of cells around it, divided by √2 (the farthest the point in
+/// its own cell can be). Values near zero mark cell centers and values near one the borders
+/// between cells.
+pub fn worley_2d(x: f32, y: f32, seed: u32) -> f32 {
+    let cell_x = x.floor() as i32;
+    let cell_y = y.floor() as i32;
+    let mut nearest = f32::INFINITY;
+
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            let cx = cell_x.wrapping_add(dx);
+            let cy = cell_y.wrapping_add(dy);
+            let hash = squirrel3_2d(cx, cy, seed);
+            let px = cx as f32 + unit(hash);
+            let py = cy as f32 + unit(squirrel3(hash, seed));
+            let distance = ((px - x).powi(2) + (py - y).powi(2)).sqrt();
+            nearest = nearest.min(distance);
+        }
+    }
+
+    (nearest / core::f32::consts::SQRT_2).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::squirrel3_2d;
+
+    use super::{fbm_2d, perlin_2d, value_noise_2d, worley_2d};
+
+    #[test]
+    fn value_noise_matches_lattice() {
+        let value = value_noise_2d(3.0, -2.0, 7);
+        assert_eq!(value, squirrel3_2d(3, -2, 7) as f32 / u32::MAX as f32);
+    }
+
+    #[test]
+    fn fbm_stays_in_range() {
+        for &(octaves, lacunarity, gain) in &[(1, 2.0, 0.5), (6, 2.0, 0.5), (4, 1.7, 1.5)] {
+            for i in -50..50 {
+                for j in -50..50 {
+                    let value = fbm_2d(
+                        i as f32 * 0.37,
+                        j as f32 * 0.53,
+                        3,
+                        octaves,
+                        lacunarity,
+                        gain,
+                    );
+                    assert!((0.0..=1.0).contains(&value), "{}", value);
+                }
+            }
+        }
+
+        assert_eq!(fbm_2d(1.5, 2.5, 3, 0, 2.0, 0.5), 0.0);
+    }
+
+    #[test]
+    fn worley_stays_in_range() {
+        let mut min = f32::INFINITY;
+        let mut max = 0.0f32;
+        for i in -50..50 {
+            for j in -50..50 {
+                let value = worley_2d(i as f32 * 0.37, j as f32 * 0.53, 3);
+                assert!((0.0..=1.0).contains(&value), "{}", value);
+                min = min.min(value);
+                max = max.max(value);
+            }
+        }
+
+        // Samples land both near feature points and out toward cell borders.
+        assert!(min < 0.1 && max > 0.4, "{} {}", min, max);
+        assert_eq!(worley_2d(1.5, 2.5, 3), worley_2d(1.5, 2.5, 3));
+    }
+
+    #[test]
+    fn perlin_is_continuous_and_in_range() {
+        let mut max = 0.0f32;
+        for i in -200..200 {
+            for j in -200..200 {
+                let (x, y) = (i as f32 * 0.037, j as f32 * 0.053);
+                let value = perlin_2d(x, y, 3);
+                assert!((-1.0..=1.0).contains(&value), "{}", value);
+                max = max.max(value.abs());
+
+                let nearby = perlin_2d(x + 0.001, y + 0.001, 3);
+                assert!((value - nearby).abs() < 0.01, "{} {}", value, nearby);
+            }
+        }
+
+        assert!(max > 0.3, "{}", max);
+        assert_eq!(perlin_2d(4.0, -7.0, 3), 0.0);
+    }
+}
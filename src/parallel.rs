@@ -0,0 +1,63 @@
+use core::ops::Range;
+
+use rayon::prelude::*;
+
+use crate::squirrel3;
+
+/// Fills `out` with the values at positions `start..` for `seed`, in parallel.
+///
+/// Each slot depends only on its own position, so the result is identical to filling the
+/// slice serially no matter how rayon schedules the work. Positions wrap at `u32::MAX`.
+pub fn fill_parallel(seed: u32, start: u32, out: &mut [u32]) {
+    out.par_iter_mut().enumerate().for_each(|(i, slot)| {
+        *slot = squirrel3(start.wrapping_add(i as u32), seed);
+    });
+}
+
+/// Computes `f(position, value)` for every position in `positions`, in parallel.
+///
+/// `value` is `squirrel3(position, seed)`. The results are collected in position order, so the
+/// output is the same as a serial `map` over the range regardless of how the work is split.
+pub fn par_map<T, F>(seed: u32, positions: Range<u32>, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(u32, u32) -> T + Sync,
+{
+    positions
+        .into_par_iter()
+        .map(|position| f(position, squirrel3(position, seed)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{squirrel3, SquirrelRng};
+
+    use super::{fill_parallel, par_map};
+
+    #[test]
+    fn parallel_fill_matches_serial() {
+        for &len in &[0, 1, 7, 1000, 100_000] {
+            let mut rng = SquirrelRng::with_seed(3).with_position(u32::MAX - 3);
+            let expected: Vec<u32> = (0..len).map(|_| rng.next_u32()).collect();
+
+            let mut actual = vec![0; len];
+            fill_parallel(3, u32::MAX - 3, &mut actual);
+
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn par_map_matches_serial() {
+        let f = |position: u32, value: u32| (position, value % 100);
+        for range in [0..0, 5..6, 10..100_000, u32::MAX - 10..u32::MAX] {
+            let expected: Vec<_> = range
+                .clone()
+                .map(|position| f(position, squirrel3(position, 3)))
+                .collect();
+
+            assert_eq!(par_map(3, range, f), expected);
+        }
+    }
+}
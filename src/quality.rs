@@ -0,0 +1,238 @@
+//! Lightweight statistical checks on raw output.
+//!
+//! None of this replaces a real battery such as PractRand (see `squirrel-stream`), but it
+//! documents the behavior callers can expect and catches gross regressions in the mixing
+//! functions. Every threshold is set well beyond the 0.1% level, so a failure means something is
+//! broken rather than unlucky.
+
+use crate::{squirrel3, squirrel5, SquirrelRng, SquirrelRng5, SquirrelRng64};
+
+const SAMPLES: usize = 100_000;
+
+/// The chi-square critical value for 255 degrees of freedom at the 0.1% level.
+const BYTE_CHI_SQUARE_LIMIT: f64 = 330.52;
+
+/// How many standard deviations a count may stray from its expectation.
+const Z_LIMIT: f64 = 5.0;
+
+/// Returns the chi-square statistic of a byte histogram against the uniform distribution.
+fn byte_chi_square(bytes: &[u8]) -> f64 {
+    let mut counts = [0u64; 256];
+    for &byte in bytes {
+        counts[byte as usize] += 1;
+    }
+
+    let expected = bytes.len() as f64 / 256.0;
+    counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+/// Returns, for each bit of a word, the z-score of how often that bit is set.
+///
+/// Under the null hypothesis each bit is set with probability one half, so its count is
+/// binomial with mean `n / 2` and standard deviation `sqrt(n) / 2`.
+fn bit_balance(words: &[u32]) -> [f64; 32] {
+    let mut counts = [0u64; 32];
+    for &word in words {
+        for (bit, count) in counts.iter_mut().enumerate() {
+            *count += u64::from(word >> bit & 1);
+        }
+    }
+
+    let n = words.len() as f64;
+    counts.map(|count| (count as f64 - n / 2.0) / (n.sqrt() / 2.0))
+}
+
+/// Returns the lag-one serial correlation coefficient of the words, treated as circular.
+///
+/// This is the statistic from Knuth's TAOCP 3.3.2. For independent uniform values it is close
+/// to zero, with a standard deviation of roughly `1 / sqrt(n)`.
+fn serial_correlation(words: &[u32]) -> f64 {
+    let n = words.len() as f64;
+    let values: Vec<f64> = words
+        .iter()
+        .map(|&word| word as f64 / u32::MAX as f64)
+        .collect();
+
+    let sum: f64 = values.iter().sum();
+    let sum_squares: f64 = values.iter().map(|value| value * value).sum();
+    let sum_products: f64 = values
+        .iter()
+        .zip(values.iter().cycle().skip(1))
+        .map(|(a, b)| a * b)
+        .sum();
+
+    (n * sum_products - sum * sum) / (n * sum_squares - sum * sum)
+}
+
+fn assert_uniform_bytes(bytes: &[u8], label: &str) {
+    let chi_square = byte_chi_square(bytes);
+    assert!(
+        chi_square < BYTE_CHI_SQUARE_LIMIT,
+        "{}: byte chi-square {}",
+        label,
+        chi_square
+    );
+}
+
+fn assert_balanced_bits(words: &[u32], label: &str) {
+    for (bit, z) in bit_balance(words).iter().enumerate() {
+        assert!(
+            z.abs() < Z_LIMIT,
+            "{}: bit {} has z-score {}",
+            label,
+            bit,
+            z
+        );
+    }
+}
+
+fn assert_uncorrelated(words: &[u32], label: &str) {
+    let correlation = serial_correlation(words);
+    let limit = Z_LIMIT / (words.len() as f64).sqrt();
+    assert!(
+        correlation.abs() < limit,
+        "{}: serial correlation {}",
+        label,
+        correlation
+    );
+}
+
+// A macro rather than a generic function, so that it works through each rng's inherent methods
+// whether or not the `rand_06` traits are implemented.
+macro_rules! assert_quality {
+    ($rng:expr, $label:expr $(,)?) => {{
+        let mut rng = $rng;
+        let label = $label;
+
+        let mut bytes = vec![0; SAMPLES * 4];
+        rng.fill_bytes(&mut bytes);
+        assert_uniform_bytes(&bytes, label);
+
+        let words: Vec<u32> = (0..SAMPLES).map(|_| rng.next_u32()).collect();
+        assert_balanced_bits(&words, label);
+        assert_uncorrelated(&words, label);
+    }};
+}
+
+#[test]
+fn statistics_detect_bad_output() {
+    // Sanity checks on the statistics themselves, so the real tests cannot pass vacuously.
+    let constant = vec![0x5555_5555; SAMPLES];
+    assert!(bit_balance(&constant).iter().all(|z| z.abs() > Z_LIMIT));
+    assert!(byte_chi_square(&[7; SAMPLES]) > BYTE_CHI_SQUARE_LIMIT);
+
+    let counter: Vec<u32> = (0..SAMPLES as u32)
+        .map(|i| i.wrapping_mul(40_503))
+        .collect();
+    assert!(serial_correlation(&counter) > 0.9);
+
+    let cyclic: Vec<u8> = (0..SAMPLES).map(|i| i as u8).collect();
+    assert!(byte_chi_square(&cyclic) < 1.0);
+}
+
+#[test]
+fn squirrel_rng_output_quality() {
+    for &seed in &[0, 1, 3, 0x4A9C19AA, u32::MAX] {
+        assert_quality!(SquirrelRng::with_seed(seed), "SquirrelRng");
+    }
+    assert_quality!(
+        SquirrelRng::with_seed(3).with_position(u32::MAX - 1_000),
+        "SquirrelRng near wrap",
+    );
+}
+
+#[test]
+fn squirrel_rng5_output_quality() {
+    for &seed in &[0, 3, u32::MAX] {
+        assert_quality!(SquirrelRng5::with_seed(seed), "SquirrelRng5");
+    }
+}
+
+#[test]
+fn squirrel_rng64_output_quality() {
+    for &seed in &[0, 3, u64::MAX] {
+        assert_quality!(SquirrelRng64::with_seed(seed), "SquirrelRng64");
+    }
+}
+
+#[test]
+fn streams_are_uncorrelated() {
+    // Raw consecutive seeds are not well mixed at a fixed position: the seed is added in before
+    // most of the mixing, and the lag-one correlation of `squirrel3(0, seed)` over successive
+    // seeds is measurably negative. Hashing the id, as `stream` does, removes it.
+    let master = SquirrelRng::with_seed(3);
+    for &position in &[0, 1, 1_000] {
+        let words: Vec<u32> = (0..SAMPLES as u32)
+            .map(|id| master.stream(id).sample_at(position))
+            .collect();
+        let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+
+        assert_uniform_bytes(&bytes, "streams");
+        assert_balanced_bits(&words, "streams");
+        assert_uncorrelated(&words, "streams");
+    }
+}
+
+/// Returns the mean number of output bits that change when `flip` is applied to the input.
+fn mean_flipped_bits(hash: &impl Fn(u32) -> u32, flip: impl Fn(u32) -> u32) -> f64 {
+    let mut total = 0u64;
+    let mut rng = SquirrelRng5::with_seed(7);
+    let inputs = 10_000;
+    for _ in 0..inputs {
+        let input = rng.next_u32();
+        total += u64::from((hash(input) ^ hash(flip(input))).count_ones());
+    }
+    total as f64 / inputs as f64
+}
+
+/// Returns the mean number of flipped output bits for each single-bit flip of the input.
+fn avalanche(hash: impl Fn(u32) -> u32) -> [f64; 32] {
+    core::array::from_fn(|bit| mean_flipped_bits(&hash, |input| input ^ 1 << bit))
+}
+
+/// Asserts that flipping an input bit flips about half the output bits, on average over the
+/// given input bits.
+fn assert_avalanche(flipped: &[f64], label: &str) {
+    let mean = flipped.iter().sum::<f64>() / flipped.len() as f64;
+    assert!((13.0..19.0).contains(&mean), "{}: mean of {}", label, mean);
+}
+
+#[test]
+fn squirrel3_avalanche() {
+    let by_position = avalanche(|position| squirrel3(position, 0x1234_5678));
+    let by_seed = avalanche(|seed| squirrel3(0x0BAD_F00D, seed));
+
+    // The first noise constant is a multiple of four, so the top two position bits never reach
+    // the output at all, and the multiply only carries upward, so the bits just below them mix
+    // noticeably less than the rest. The low half of either input is close to ideal.
+    assert_eq!(by_position[30..], [0.0, 0.0]);
+    assert_avalanche(&by_position[..30], "squirrel3 position");
+    assert_avalanche(&by_seed, "squirrel3 seed");
+    for bit in 0..16 {
+        assert!(
+            (15.0..17.0).contains(&by_position[bit]),
+            "position bit {}",
+            bit
+        );
+        assert!((15.0..17.0).contains(&by_seed[bit]), "seed bit {}", bit);
+    }
+}
+
+#[test]
+fn squirrel5_avalanche() {
+    let by_position = avalanche(|position| squirrel5(position, 0x1234_5678));
+    let by_seed = avalanche(|seed| squirrel5(0x0BAD_F00D, seed));
+
+    // The extra rounds fix squirrel3's weak high bits: every input bit is close to ideal.
+    for bit in 0..32 {
+        assert!(
+            (15.5..16.5).contains(&by_position[bit]),
+            "position bit {}",
+            bit
+        );
+        assert!((15.5..16.5).contains(&by_seed[bit]), "seed bit {}", bit);
+    }
+}
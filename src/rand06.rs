@@ -0,0 +1,112 @@
+//! Implementations of the `rand_core` 0.6 traits, for use with `rand` 0.8.
+//!
+//! Every method here forwards to the inherent method of the same name, so the output is the
+//! same whether or not the traits are used.
+
+use rand::{RngCore, SeedableRng};
+
+#[cfg(feature = "std")]
+use crate::SquirrelThreadRng;
+use crate::{compat::Lcg32, SquirrelRng, SquirrelRng5, SquirrelRng64};
+
+macro_rules! impl_rand_06 {
+    ($(#[$seedable:meta])* $rng:ty, $seed:ty) => {
+        impl RngCore for $rng {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                <$rng>::next_u32(self)
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                <$rng>::next_u64(self)
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                <$rng>::fill_bytes(self, dest);
+            }
+
+            #[inline]
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+                <$rng>::fill_bytes(self, dest);
+                Ok(())
+            }
+        }
+
+        $(#[$seedable])*
+        impl SeedableRng for $rng {
+            type Seed = [u8; core::mem::size_of::<$seed>()];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self::with_seed(<$seed>::from_le_bytes(seed))
+            }
+        }
+    };
+}
+
+impl_rand_06!(
+    /// The seed covers only the `seed` field; rngs built this way always start at position 0.
+    /// Use [`SquirrelRng::from_state`] to restore an rng partway through its stream.
+    SquirrelRng,
+    u32
+);
+impl_rand_06!(SquirrelRng5, u32);
+impl_rand_06!(SquirrelRng64, u64);
+impl_rand_06!(Lcg32, u32);
+
+#[cfg(feature = "std")]
+impl RngCore for SquirrelThreadRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        SquirrelThreadRng::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        SquirrelThreadRng::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        SquirrelThreadRng::fill_bytes(self, dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        SquirrelThreadRng::fill_bytes(self, dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, RngCore, SeedableRng};
+
+    use crate::{compat::Lcg32, SquirrelRng, SquirrelRng5, SquirrelRng64};
+
+    #[test]
+    fn rand_06_matches_inherent_methods() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+
+        assert_eq!(RngCore::next_u32(&mut a), b.next_u32());
+        assert_eq!(RngCore::next_u64(&mut a), b.next_u64());
+
+        let mut x = [0; 11];
+        let mut y = [0; 11];
+        RngCore::fill_bytes(&mut a, &mut x);
+        b.fill_bytes(&mut y);
+        assert_eq!(x, y);
+        assert_eq!(a.gen::<u32>(), b.next_u32());
+
+        let seeded: SquirrelRng = SeedableRng::from_seed([1, 2, 3, 4]);
+        assert_eq!(seeded, SquirrelRng::with_seed(0x0403_0201));
+        let seeded: SquirrelRng5 = SeedableRng::from_seed([1, 2, 3, 4]);
+        assert_eq!(seeded, SquirrelRng5::with_seed(0x0403_0201));
+        let seeded: SquirrelRng64 = SeedableRng::from_seed([1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(seeded, SquirrelRng64::with_seed(0x0807_0605_0403_0201));
+        let seeded: Lcg32 = SeedableRng::from_seed([1, 2, 3, 4]);
+        assert_eq!(seeded, Lcg32::with_seed(0x0403_0201));
+    }
+}
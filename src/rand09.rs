@@ -0,0 +1,67 @@
+//! Implementations of the `rand_core` 0.9 traits, for use with `rand` 0.9.
+//!
+//! These can be enabled alongside the `rand_core` 0.6 implementations of the `rand_06` feature
+//! or on their own. Both forward to the same inherent methods, so they produce exactly the same
+//! output.
+
+use rand_core_09::{RngCore, SeedableRng};
+
+use crate::{SquirrelRng, SquirrelRng5, SquirrelRng64};
+
+macro_rules! impl_rand_09 {
+    ($rng:ty, $seed:ty) => {
+        impl RngCore for $rng {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                <$rng>::next_u32(self)
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                <$rng>::next_u64(self)
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                <$rng>::fill_bytes(self, dest);
+            }
+        }
+
+        impl SeedableRng for $rng {
+            type Seed = [u8; core::mem::size_of::<$seed>()];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self::with_seed(<$seed>::from_le_bytes(seed))
+            }
+        }
+    };
+}
+
+impl_rand_09!(SquirrelRng, u32);
+impl_rand_09!(SquirrelRng5, u32);
+impl_rand_09!(SquirrelRng64, u64);
+
+#[cfg(test)]
+mod tests {
+    use rand_core_09::{RngCore, SeedableRng};
+
+    use crate::SquirrelRng;
+
+    #[test]
+    fn rand_09_matches_inherent_methods() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+
+        assert_eq!(RngCore::next_u32(&mut a), b.next_u32());
+        assert_eq!(RngCore::next_u64(&mut a), b.next_u64());
+
+        let mut x = [0; 11];
+        let mut y = [0; 11];
+        RngCore::fill_bytes(&mut a, &mut x);
+        b.fill_bytes(&mut y);
+        assert_eq!(x, y);
+
+        let seeded: SquirrelRng = SeedableRng::from_seed([1, 2, 3, 4]);
+        assert_eq!(seeded, SquirrelRng::with_seed(0x0403_0201));
+    }
+}
@@ -0,0 +1,471 @@
+//! Operations on slices and index sets.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+
+use crate::{squirrel3_2d, SquirrelRng};
+
+impl SquirrelRng {
+    /// Returns a reference to a random element of `slice`, or `None` if it is empty.
+    ///
+    /// Exactly one word is consumed for a nonempty slice (via
+    /// [`gen_bounded`](Self::gen_bounded)), so the pick is determined by the position and seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is longer than `u32::MAX` elements.
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+        assert!(
+            slice.len() <= u32::MAX as usize,
+            "slice is too long to choose from"
+        );
+        slice.get(self.gen_bounded(slice.len() as u32) as usize)
+    }
+
+    /// Shuffles `slice` in place using Fisher-Yates.
+    ///
+    /// Each swap draws from [`gen_bounded`](Self::gen_bounded), so a slice of length `n`
+    /// consumes exactly `n - 1` words (none if `n < 2`) and the resulting order is fully
+    /// determined by the starting position and seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is longer than `u32::MAX` elements.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        assert!(
+            slice.len() <= u32::MAX as usize,
+            "slice is too long to shuffle"
+        );
+        for i in (1..slice.len()).rev() {
+            let j = self.gen_bounded(i as u32 + 1);
+            slice.swap(i, j as usize);
+        }
+    }
+
+    /// Returns the four cardinal neighbors of cell `(x, y)` in an order fixed by the cell and
+    /// `seed`.
+    ///
+    /// The order comes from [`squirrel3_2d`] of the cell, so it is the same no matter when or in
+    /// what traversal the cell is visited. This makes it a building block for maze generators
+    /// that carve chunks independently. Coordinates wrap at the `i32` boundary.
+    ///
+    /// This neither reads nor advances the rng, so the result does not depend on its state.
+    pub fn shuffled_neighbors(&mut self, x: i32, y: i32, seed: u32) -> [(i32, i32); 4] {
+        let mut neighbors = [
+            (x, y.wrapping_sub(1)),
+            (x.wrapping_add(1), y),
+            (x, y.wrapping_add(1)),
+            (x.wrapping_sub(1), y),
+        ];
+        SquirrelRng::with_seed(squirrel3_2d(x, y, seed)).shuffle(&mut neighbors);
+        neighbors
+    }
+
+    /// Returns the integers `0..n` in a random order.
+    ///
+    /// This is [`shuffle`](Self::shuffle) applied to `0..n`, so it consumes `n - 1` words (none
+    /// if `n < 2`) and the order is determined by the starting position and seed.
+    #[cfg(feature = "alloc")]
+    pub fn permutation(&mut self, n: u32) -> Vec<u32> {
+        let mut values: Vec<u32> = (0..n).collect();
+        self.shuffle(&mut values);
+        values
+    }
+
+    /// Reorders `items` randomly, favoring heavier items toward the front.
+    ///
+    /// This is the Efraimidis-Spirakis method: every item gets the key `u^(1 / weight)` for a
+    /// fresh uniform `u`, and the items are sorted by descending key. The first item is therefore
+    /// picked with probability proportional to its weight, the second likewise from the rest,
+    /// and so on. Keys are compared as `ln(u) / weight`, which orders them identically with
+    /// better precision. Zero-weight items go to the back in their original order.
+    ///
+    /// Exactly one [`next_f64`](Self::next_f64) (two words) is drawn per item, in order, so the
+    /// result is determined by the starting position and seed.
+    #[cfg(feature = "std")]
+    pub fn shuffle_weighted<T>(&mut self, items: &mut [(T, u32)]) {
+        let mut keys: Vec<(f64, usize)> = items
+            .iter()
+            .enumerate()
+            .map(|(i, &(_, weight))| {
+                let u = 1.0 - self.next_f64();
+                let key = if weight == 0 {
+                    f64::NEG_INFINITY
+                } else {
+                    u.ln() / f64::from(weight)
+                };
+                (key, i)
+            })
+            .collect();
+        keys.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        // Apply the permutation in place: slot `i` takes the item that started at `keys[i].1`.
+        // Sources below `i` have already been swapped away, so follow the chain to find where
+        // that item is now.
+        for i in 0..items.len() {
+            let mut source = keys[i].1;
+            while source < i {
+                source = keys[source].1;
+            }
+            items.swap(i, source);
+        }
+    }
+
+    /// Picks up to `n` items without replacement, each with probability proportional to its
+    /// weight.
+    ///
+    /// This is weighted reservoir sampling (A-Res): the chosen items are the `n` with the largest
+    /// keys from [`shuffle_weighted`](Self::shuffle_weighted), returned in that order, so the
+    /// heaviest items tend to come first. Zero-weight items are never chosen, so fewer than `n`
+    /// items come back when fewer than `n` have nonzero weight. Two words are consumed per item.
+    #[cfg(feature = "std")]
+    pub fn weighted_sample_n<T>(&mut self, mut items: Vec<(T, u32)>, n: usize) -> Vec<T> {
+        self.shuffle_weighted(&mut items);
+        items
+            .into_iter()
+            .take(n)
+            .filter(|&(_, weight)| weight > 0)
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// Returns `k` distinct indices from `0..n`, in no particular order.
+    ///
+    /// Exactly `k` words are consumed. When `k` is small relative to `n`, Floyd's algorithm
+    /// avoids materializing the whole range; otherwise a partial Fisher-Yates shuffle of `0..n`
+    /// is used. Either way the result is determined by the starting position and seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > n`.
+    #[cfg(feature = "alloc")]
+    pub fn sample_indices(&mut self, n: u32, k: u32) -> Vec<u32> {
+        assert!(k <= n, "cannot sample {} distinct indices from {}", k, n);
+
+        if u64::from(k) * u64::from(k) < u64::from(n) {
+            // Floyd's algorithm.
+            let mut indices = Vec::with_capacity(k as usize);
+            for j in n - k..n {
+                let t = self.gen_bounded(j + 1);
+                if indices.contains(&t) {
+                    indices.push(j);
+                } else {
+                    indices.push(t);
+                }
+            }
+            indices
+        } else {
+            let mut indices: Vec<u32> = (0..n).collect();
+            for i in 0..k {
+                let j = i + self.gen_bounded(n - i);
+                indices.swap(i as usize, j as usize);
+            }
+            indices.truncate(k as usize);
+            indices
+        }
+    }
+
+    /// Samples up to `k` items uniformly from `iter`, whose length need not be known.
+    ///
+    /// This is Algorithm R: the first `k` items fill the reservoir, after which each further
+    /// item consumes exactly one word to decide whether it replaces a reservoir slot. The result
+    /// has `min(k, len)` items and is determined by the starting position and seed. When `k` is
+    /// zero, the iterator is not consumed at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `u32::MAX` items.
+    #[cfg(feature = "alloc")]
+    pub fn reservoir_sample<T, I: Iterator<Item = T>>(&mut self, iter: I, k: usize) -> Vec<T> {
+        let mut reservoir = Vec::with_capacity(k);
+        if k == 0 {
+            return reservoir;
+        }
+
+        for (i, item) in iter.enumerate() {
+            if i < k {
+                reservoir.push(item);
+            } else {
+                let bound = u32::try_from(i + 1).expect("too many items to sample");
+                let j = self.gen_bounded(bound) as usize;
+                if j < k {
+                    reservoir[j] = item;
+                }
+            }
+        }
+        reservoir
+    }
+
+    /// Picks an index with probability proportional to its weight, consuming exactly one `u32`.
+    ///
+    /// Zero weights are never picked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty or all of its weights are zero. See
+    /// [`try_weighted_index`](Self::try_weighted_index) for a non-panicking version.
+    pub fn weighted_index(&mut self, weights: &[u32]) -> usize {
+        self.try_weighted_index(weights)
+            .expect("weights must contain at least one nonzero weight")
+    }
+
+    /// Picks the next state of a Markov chain from `row`, the weights of the transitions out of
+    /// the current state.
+    ///
+    /// This is [`weighted_index`](Self::weighted_index) under a name that fits the use case; see
+    /// [`MarkovChain`](crate::MarkovChain) for a wrapper that tracks the current state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is empty or all of its weights are zero.
+    pub fn next_transition(&mut self, row: &[u32]) -> usize {
+        self.weighted_index(row)
+    }
+
+    /// Like [`weighted_index`](Self::weighted_index), but returns `None` instead of panicking.
+    ///
+    /// No word is consumed when `None` is returned.
+    pub fn try_weighted_index(&mut self, weights: &[u32]) -> Option<usize> {
+        let total: u64 = weights.iter().map(|&weight| u64::from(weight)).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let target = ((u128::from(self.next_u32()) * u128::from(total)) >> 32) as u64;
+        let mut cumulative = 0;
+        weights.iter().position(|&weight| {
+            cumulative += u64::from(weight);
+            cumulative > target
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    #[test]
+    fn choose_is_position_stable() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let empty: [u32; 0] = [];
+
+        assert_eq!(rng.choose(&empty), None);
+        assert_eq!(rng.position(), 0);
+
+        let items = ["a", "b", "c", "d", "e"];
+        let picked = rng.choose(&items);
+        assert_eq!(picked, SquirrelRng::with_seed(3).choose(&items));
+        assert_eq!(rng.position(), 1);
+    }
+
+    #[test]
+    fn shuffle_is_stable() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut values: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut values);
+
+        assert_eq!(values, [7, 4, 1, 5, 3, 9, 0, 8, 2, 6]);
+        assert_eq!(rng.position(), 9);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn permutation_contains_every_index_once() {
+        let mut rng = SquirrelRng::with_seed(3);
+        assert_eq!(rng.permutation(10), [7, 4, 1, 5, 3, 9, 0, 8, 2, 6]);
+        assert_eq!(rng.position(), 9);
+
+        for n in [0, 1, 2, 100] {
+            let mut values = SquirrelRng::with_seed(3).permutation(n);
+            values.sort_unstable();
+            assert!(values.into_iter().eq(0..n));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn shuffle_weighted_favors_heavy_items() {
+        let mut descending = 0;
+        for seed in 0..1_000 {
+            let mut items = [('a', 1), ('b', 1_000_000), ('z', 0), ('c', 1_000)];
+            let mut rng = SquirrelRng::with_seed(seed);
+            rng.shuffle_weighted(&mut items);
+
+            assert_eq!(rng.position(), 8);
+            assert_eq!(items[3], ('z', 0));
+            if items[..3] == [('b', 1_000_000), ('c', 1_000), ('a', 1)] {
+                descending += 1;
+            }
+        }
+        assert!(descending >= 990, "{}", descending);
+
+        let mut a: Vec<(u32, u32)> = (0..20).map(|i| (i, i % 5)).collect();
+        let mut b = a.clone();
+        SquirrelRng::with_seed(3).shuffle_weighted(&mut a);
+        SquirrelRng::with_seed(3).shuffle_weighted(&mut b);
+        assert_eq!(a, b);
+
+        let mut sorted = a.clone();
+        sorted.sort_unstable();
+        assert!(sorted.iter().map(|&(i, _)| i).eq(0..20));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn weighted_sample_n_prefers_heavy_items() {
+        let items = || {
+            vec![
+                ("common", 100),
+                ("rare", 10),
+                ("none", 0),
+                ("epic", 1),
+                ("also", 100),
+            ]
+        };
+        let mut picked_rare = 0;
+        let mut picked_epic = 0;
+        for seed in 0..1_000 {
+            let mut rng = SquirrelRng::with_seed(seed);
+            let mut sample = rng.weighted_sample_n(items(), 3);
+
+            assert_eq!(rng.position(), 10);
+            assert_eq!(sample.len(), 3);
+            assert!(!sample.contains(&"none"));
+            picked_rare += sample.contains(&"rare") as u32;
+            picked_epic += sample.contains(&"epic") as u32;
+
+            sample.sort_unstable();
+            sample.dedup();
+            assert_eq!(sample.len(), 3);
+        }
+        assert!(
+            picked_rare > picked_epic * 3,
+            "{} {}",
+            picked_rare,
+            picked_epic
+        );
+
+        let mut rng = SquirrelRng::with_seed(3);
+        assert_eq!(rng.weighted_sample_n(items(), 10).len(), 4);
+        assert!(rng.weighted_sample_n(items(), 0).is_empty());
+        assert_eq!(
+            SquirrelRng::with_seed(3).weighted_sample_n(items(), 2),
+            SquirrelRng::with_seed(3).weighted_sample_n(items(), 2)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sample_indices_are_distinct_and_in_range() {
+        for &(n, k) in &[(0, 0), (10, 0), (10, 10), (10, 3), (1000, 5), (1000, 900)] {
+            let mut rng = SquirrelRng::with_seed(3);
+            let mut indices = rng.sample_indices(n, k);
+
+            assert_eq!(rng.position(), k);
+            assert_eq!(indices, SquirrelRng::with_seed(3).sample_indices(n, k));
+
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), k as usize);
+            assert!(indices.iter().all(|&index| index < n));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn sample_indices_rejects_k_greater_than_n() {
+        SquirrelRng::with_seed(3).sample_indices(3, 4);
+    }
+
+    #[test]
+    fn weighted_index_tracks_weights() {
+        let weights = [1, 0, 2, 3, 4];
+        let mut rng = SquirrelRng::with_seed(3);
+        let mut counts = [0u32; 5];
+        for _ in 0..100_000 {
+            counts[rng.weighted_index(&weights)] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+        let chi_square: f64 = weights
+            .iter()
+            .zip(counts)
+            .filter(|&(&weight, _)| weight > 0)
+            .map(|(&weight, count)| {
+                let expected = 100_000.0 * weight as f64 / 10.0;
+                (count as f64 - expected).powi(2) / expected
+            })
+            .sum();
+
+        // Three degrees of freedom; 16.27 is the 0.1% critical value.
+        assert!(chi_square < 16.27, "{}", chi_square);
+    }
+
+    #[test]
+    fn weighted_index_edge_cases() {
+        let mut rng = SquirrelRng::with_seed(3);
+
+        assert_eq!(rng.try_weighted_index(&[]), None);
+        assert_eq!(rng.try_weighted_index(&[0, 0]), None);
+        assert_eq!(rng.position(), 0);
+        assert_eq!(rng.weighted_index(&[0, 5, 0]), 1);
+        assert!(rng.weighted_index(&[u32::MAX, u32::MAX]) < 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn reservoir_sample_size_and_reproducibility() {
+        for &(len, k) in &[(0usize, 3), (2, 3), (3, 3), (100, 3), (100, 0)] {
+            let mut rng = SquirrelRng::with_seed(3);
+            let sample = rng.reservoir_sample(0..len, k);
+
+            let expected_words = if k == 0 { 0 } else { len.saturating_sub(k) };
+
+            assert_eq!(sample.len(), k.min(len));
+            assert_eq!(rng.position() as usize, expected_words);
+            assert_eq!(
+                sample,
+                SquirrelRng::with_seed(3).reservoir_sample(0..len, k)
+            );
+            assert!(sample.iter().all(|&item| item < len));
+        }
+    }
+
+    #[test]
+    fn shuffled_neighbors_are_fixed_per_cell() {
+        let mut rng = SquirrelRng::with_seed(7).with_position(10);
+        let mut orders = Vec::new();
+        for (x, y) in [(0, 0), (5, -3), (i32::MAX, i32::MIN)] {
+            let order = rng.shuffled_neighbors(x, y, 3);
+            assert_eq!(order, SquirrelRng::with_seed(8).shuffled_neighbors(x, y, 3));
+            assert_eq!(rng.position(), 10);
+
+            let mut sorted = order;
+            sorted.sort_unstable();
+            let mut expected = [
+                (x, y.wrapping_sub(1)),
+                (x.wrapping_add(1), y),
+                (x, y.wrapping_add(1)),
+                (x.wrapping_sub(1), y),
+            ];
+            expected.sort_unstable();
+            assert_eq!(sorted, expected);
+        }
+
+        // Across many cells, every one of the 24 orders turns up.
+        for x in 0..1_000 {
+            let order = rng.shuffled_neighbors(x, 0, 3);
+            let offsets = order.map(|(nx, ny)| (nx - x, ny));
+            if !orders.contains(&offsets) {
+                orders.push(offsets);
+            }
+        }
+        assert_eq!(orders.len(), 24);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{squirrel3, squirrel3_2d, squirrel3_3d, u32_to_unit_f32};
+
+/// A stateless noise source: a seed, with no cursor.
+///
+/// Where [`SquirrelRng`](crate::SquirrelRng) is a stream that advances as it is read, a
+/// `Squirrel` is a pure function of position. Nothing about it can change after construction, so
+/// it can be shared freely without any risk of accidentally consuming values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Squirrel {
+    seed: u32,
+}
+
+impl Squirrel {
+    pub const fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    pub const fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    /// Returns the value at `position`. Shorthand for [`get_u32`](Self::get_u32).
+    #[inline]
+    pub const fn get(&self, position: u32) -> u32 {
+        self.get_u32(position)
+    }
+
+    /// Returns the value at `position`.
+    #[inline]
+    pub const fn get_u32(&self, position: u32) -> u32 {
+        squirrel3(position, self.seed)
+    }
+
+    /// Returns the value at `position` mapped to `[0, 1)`, exactly as
+    /// [`SquirrelRng::next_f32`](crate::SquirrelRng::next_f32) would.
+    #[inline]
+    pub const fn get_f32(&self, position: u32) -> f32 {
+        u32_to_unit_f32(self.get_u32(position))
+    }
+
+    /// Returns the value at `(x, y)`; see [`squirrel3_2d`].
+    #[inline]
+    pub const fn get_2d(&self, x: i32, y: i32) -> u32 {
+        squirrel3_2d(x, y, self.seed)
+    }
+
+    /// Returns the value at `(x, y, z)`; see [`squirrel3_3d`].
+    #[inline]
+    pub const fn get_3d(&self, x: i32, y: i32, z: i32) -> u32 {
+        squirrel3_3d(x, y, z, self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{squirrel3, squirrel3_2d, squirrel3_3d, SquirrelRng};
+
+    use super::Squirrel;
+
+    #[test]
+    fn get_matches_squirrel3() {
+        let source = Squirrel::new(3);
+        for position in [0, 1, 1000, u32::MAX] {
+            assert_eq!(source.get(position), squirrel3(position, 3));
+        }
+    }
+
+    #[test]
+    fn accessors_match_free_functions() {
+        let source = Squirrel::new(3);
+
+        assert_eq!(source.get_u32(7), squirrel3(7, 3));
+        assert_eq!(
+            source.get_f32(7),
+            SquirrelRng::with_seed(3).with_position(7).next_f32()
+        );
+        assert_eq!(source.get_2d(-1, 2), squirrel3_2d(-1, 2, 3));
+        assert_eq!(source.get_3d(-1, 2, 5), squirrel3_3d(-1, 2, 5, 3));
+    }
+}
@@ -0,0 +1,141 @@
+use rand::Rng;
+
+use crate::fill_bytes_via_u64;
+
+/// A 64-bit counterpart to [`SquirrelRng`](crate::SquirrelRng).
+///
+/// Position and seed are both `u64`, so the stream does not wrap for any practical purpose.
+/// `next_u64` is the native operation here; `next_u32` simply returns the low word of the next
+/// `u64`. The streams produced by this type are unrelated to those of the 32-bit rng, even for
+/// the same seed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SquirrelRng64 {
+    position: u64,
+    seed: u64,
+}
+
+impl SquirrelRng64 {
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        Self::seed_from(rand::thread_rng())
+    }
+
+    pub fn seed_from(mut rng: impl Rng) -> Self {
+        Self::with_seed(rng.next_u64())
+    }
+
+    pub const fn with_seed(seed: u64) -> Self {
+        Self { position: 0, seed }
+    }
+
+    pub const fn with_position(self, position: u64) -> Self {
+        Self { position, ..self }
+    }
+
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the value at `position` for this rng's seed.
+    ///
+    /// This does not advance (or otherwise touch) the rng's own position.
+    #[inline]
+    pub fn sample_at(&self, position: u64) -> u64 {
+        squirrel3_64(position, self.seed)
+    }
+
+    /// Returns the low word of the next value.
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    /// Returns the value at the current position, then advances by one.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let result = squirrel3_64(self.position, self.seed);
+        self.position = self.position.wrapping_add(1);
+        result
+    }
+
+    /// Fills `dest` with the next values, eight little-endian bytes each.
+    #[inline]
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        fill_bytes_via_u64(dest, || self.next_u64());
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for SquirrelRng64 {
+    fn default() -> Self {
+        SquirrelRng64::new()
+    }
+}
+
+/// The 64-bit analog of [`squirrel3`](crate::squirrel3).
+///
+/// The mixing steps are the same, but the noise constants are 64 bits wide and the shifts are
+/// doubled to match.
+#[inline]
+pub const fn squirrel3_64(position: u64, seed: u64) -> u64 {
+    const BIT_NOISE1: u64 = 0x9E3779B97F4A7C15;
+    const BIT_NOISE2: u64 = 0xBF58476D1CE4E5B9;
+    const BIT_NOISE3: u64 = 0x94D049BB133111EB;
+
+    let mut mangled = position;
+    mangled = mangled.wrapping_mul(BIT_NOISE1);
+    mangled = mangled.wrapping_add(seed);
+    mangled ^= mangled >> 16;
+    mangled = mangled.wrapping_add(BIT_NOISE2);
+    mangled ^= mangled << 16;
+    mangled = mangled.wrapping_mul(BIT_NOISE3);
+    mangled ^= mangled >> 16;
+    mangled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{squirrel3_64, SquirrelRng64};
+
+    #[test]
+    fn golden_values() {
+        let mut rng = SquirrelRng64::with_seed(0);
+
+        assert_eq!(rng.next_u64(), 0xAF62DC3DAB83F10F);
+        assert_eq!(rng.next_u64(), 0x9A9CF4AA0EDBF1E5);
+        assert_eq!(squirrel3_64(u64::MAX, 3), 0xCB7BAB865DD87479);
+    }
+
+    #[test]
+    fn next_u32_is_low_word_of_next_u64() {
+        let mut a = SquirrelRng64::with_seed(3);
+        let mut b = a;
+
+        assert_eq!(a.next_u32(), b.next_u64() as u32);
+        assert_eq!(a.position(), b.position());
+    }
+
+    #[test]
+    fn fill_bytes_tails_come_from_one_word() {
+        // Any tail shorter than a word takes the low bytes of a single `next_u64`, whether it
+        // goes through the `next_u32` branch (1 to 4 bytes) or the `next_u64` branch (5 to 7).
+        let word = squirrel3_64(0, 3).to_le_bytes();
+        for len in 1..8 {
+            let mut rng = SquirrelRng64::with_seed(3);
+            let mut dest = [0u8; 7];
+            rng.fill_bytes(&mut dest[..len]);
+
+            assert_eq!(dest[..len], word[..len], "length {}", len);
+            assert_eq!(rng.position(), 1);
+        }
+    }
+}
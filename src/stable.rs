@@ -0,0 +1,126 @@
+//! Range sampling that always consumes exactly one word.
+//!
+//! `Rng::gen_range` from `rand` rejects and redraws to remove bias, so the number of words it
+//! consumes depends on the bound and on the values drawn. Changing a range anywhere in a
+//! program then shifts every draw after it. [`SquirrelRng::gen_range_stable`] never does this:
+//! every call advances the position by exactly one, whatever the type and bound, in exchange for
+//! the tiny bias described on [`gen_bounded`](SquirrelRng::gen_bounded).
+
+use core::ops::Range;
+
+use crate::{next_down_f64, SquirrelRng};
+
+/// A type that [`SquirrelRng::gen_range_stable`] can sample.
+///
+/// Implementations must consume exactly one `u32` from the rng for every nonempty range.
+pub trait SampleStable: Sized {
+    /// Draws a value in `range` from `rng`, consuming exactly one word.
+    fn sample_stable(rng: &mut SquirrelRng, range: Range<Self>) -> Self;
+}
+
+impl SampleStable for u32 {
+    /// Maps the word with [`gen_bounded`](SquirrelRng::gen_bounded).
+    #[inline]
+    fn sample_stable(rng: &mut SquirrelRng, range: Range<Self>) -> Self {
+        let Range { start, end } = range;
+        assert!(start < end, "cannot sample empty range");
+        start + rng.gen_bounded(end - start)
+    }
+}
+
+impl SampleStable for i32 {
+    /// The same as [`gen_range_i32`](SquirrelRng::gen_range_i32).
+    #[inline]
+    fn sample_stable(rng: &mut SquirrelRng, range: Range<Self>) -> Self {
+        rng.gen_range_i32(range)
+    }
+}
+
+impl SampleStable for f32 {
+    /// The same as [`gen_range_f32`](SquirrelRng::gen_range_f32).
+    #[inline]
+    fn sample_stable(rng: &mut SquirrelRng, range: Range<Self>) -> Self {
+        rng.gen_range_f32(range)
+    }
+}
+
+impl SampleStable for f64 {
+    /// Scales the word, divided by 2^32, onto the range.
+    ///
+    /// Only 32 bits of randomness go into the result, so for narrow ranges far fewer distinct
+    /// values are possible than with [`next_f64`](SquirrelRng::next_f64). If float rounding
+    /// would land on `range.end`, the largest float below it is returned instead.
+    fn sample_stable(rng: &mut SquirrelRng, range: Range<Self>) -> Self {
+        let Range { start, end } = range;
+        assert!(start < end, "cannot sample empty range");
+        let span = end - start;
+        assert!(span.is_finite(), "range is too wide to sample");
+
+        let unit = f64::from(rng.next_u32()) * (1.0 / (1u64 << 32) as f64);
+        let value = start + unit * span;
+        if value < end {
+            value
+        } else {
+            next_down_f64(end)
+        }
+    }
+}
+
+impl SquirrelRng {
+    /// Returns a value in `[range.start, range.end)`, consuming exactly one `u32`.
+    ///
+    /// The position advances by one no matter the type or the bound, so changing a range never
+    /// shifts later draws. See the [`stable`](crate::stable) module.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or for floats, too wide for its length to be finite.
+    #[inline]
+    pub fn gen_range_stable<T: SampleStable>(&mut self, range: Range<T>) -> T {
+        T::sample_stable(self, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    #[test]
+    fn every_range_consumes_one_word() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for &bound in &[1u32, 2, 3, 7, 1 << 31, u32::MAX] {
+            let start = rng.position();
+            let value = rng.gen_range_stable(5..5u32.saturating_add(bound));
+            assert!(value >= 5);
+            let int = rng.gen_range_stable(-(bound as i64 / 2) as i32..1);
+            assert!(int <= 0);
+            let float = rng.gen_range_stable(-1.0f32..bound as f32);
+            assert!(float < bound as f32);
+            let double = rng.gen_range_stable(0.0..f64::from(bound) / 1e9);
+            assert!(double < f64::from(bound) / 1e9);
+
+            assert_eq!(rng.position(), start + 4, "bound {}", bound);
+        }
+    }
+
+    #[test]
+    fn matches_dedicated_methods() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+        assert_eq!(a.gen_range_stable(10..20u32), 10 + b.gen_bounded(10));
+        assert_eq!(a.gen_range_stable(-5..5), b.gen_range_i32(-5..5));
+        assert_eq!(
+            a.gen_range_stable(0.5f32..2.0),
+            b.gen_range_f32(0.5f32..2.0)
+        );
+    }
+
+    #[test]
+    fn f64_range_stays_below_end() {
+        let mut rng = SquirrelRng::with_seed(3);
+        for _ in 0..10_000 {
+            let value = rng.gen_range_stable(1.0..1.0 + f64::EPSILON * 4.0);
+            assert!((1.0..1.0 + f64::EPSILON * 4.0).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Random strings.
+
+use alloc::string::String;
+
+use crate::SquirrelRng;
+
+/// The alphabet used by [`SquirrelRng::gen_token`].
+pub const TOKEN_ALPHABET: &[u8; 62] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+impl SquirrelRng {
+    /// Returns a string of `len` characters drawn from [`TOKEN_ALPHABET`] (`[A-Za-z0-9]`).
+    ///
+    /// Each character consumes exactly one word via [`gen_bounded`](Self::gen_bounded), so the
+    /// token is determined by the starting position and seed. Not suitable for secrets.
+    pub fn gen_token(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| TOKEN_ALPHABET[self.gen_bounded(TOKEN_ALPHABET.len() as u32) as usize] as char)
+            .collect()
+    }
+
+    /// Returns a string of `len` decimal digits, such as a PIN or verification code.
+    ///
+    /// Each digit consumes exactly one word via [`gen_bounded`](Self::gen_bounded). Leading
+    /// zeros are kept. Not suitable for secrets.
+    pub fn gen_digits(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| char::from(b'0' + self.gen_bounded(10) as u8))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    #[test]
+    fn token_is_alphanumeric_and_stable() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let token = rng.gen_token(32);
+
+        assert_eq!(token.len(), 32);
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_eq!(rng.position(), 32);
+        assert_eq!(token, SquirrelRng::with_seed(3).gen_token(32));
+        assert_eq!(SquirrelRng::with_seed(3).gen_token(12), "lQTEJnCdKBrd");
+    }
+
+    #[test]
+    fn digits_are_decimal_and_stable() {
+        let mut rng = SquirrelRng::with_seed(3);
+        let digits = rng.gen_digits(1_000);
+
+        assert_eq!(digits.len(), 1_000);
+        assert!(digits.chars().all(|c| c.is_ascii_digit()));
+        assert!(('0'..='9').all(|d| digits.contains(d)));
+        assert_eq!(rng.position(), 1_000);
+        assert_eq!(SquirrelRng::with_seed(3).gen_digits(6), digits[..6]);
+        assert!(rng.gen_digits(0).is_empty());
+    }
+}
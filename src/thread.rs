@@ -0,0 +1,71 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::SquirrelRng;
+
+thread_local! {
+    static THREAD_RNG: Rc<RefCell<SquirrelRng>> = Rc::new(RefCell::new(SquirrelRng::new()));
+}
+
+/// Returns a handle to this thread's lazily seeded [`SquirrelRng`].
+///
+/// This is the Squirrel analog of `rand::thread_rng`. The rng is seeded from `thread_rng` the
+/// first time it is used on each thread, and every handle on the same thread shares (and
+/// advances) the same stream.
+pub fn squirrel_rng() -> SquirrelThreadRng {
+    SquirrelThreadRng {
+        rng: THREAD_RNG.with(Rc::clone),
+    }
+}
+
+/// A handle to the thread-local rng returned by [`squirrel_rng`].
+///
+/// With the `rand_06` feature, the handle implements `RngCore`, so the `rand::Rng` extension
+/// methods work on it directly.
+/// Use [`with`](Self::with) to reach the inherent `SquirrelRng` methods.
+#[derive(Debug, Clone)]
+pub struct SquirrelThreadRng {
+    rng: Rc<RefCell<SquirrelRng>>,
+}
+
+impl SquirrelThreadRng {
+    /// Runs `f` with mutable access to the underlying rng.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called re-entrantly, i.e. if `f` itself uses a thread rng handle.
+    pub fn with<T>(&self, f: impl FnOnce(&mut SquirrelRng) -> T) -> T {
+        f(&mut self.rng.borrow_mut())
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> u32 {
+        self.rng.borrow_mut().next_u32()
+    }
+
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        self.rng.borrow_mut().next_u64()
+    }
+
+    #[inline]
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.borrow_mut().fill_bytes(dest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::squirrel_rng;
+
+    #[test]
+    fn handles_share_one_stream() {
+        let mut expected = squirrel_rng().with(|rng| *rng);
+
+        assert_eq!(squirrel_rng().next_u32(), expected.next_u32());
+        assert_eq!(squirrel_rng().next_u32(), expected.next_u32());
+        assert_eq!(
+            squirrel_rng().with(|rng| rng.gen_bounded(10)),
+            expected.gen_bounded(10)
+        );
+    }
+}
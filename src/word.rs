@@ -0,0 +1,67 @@
+//! Generic access to the fixed-width integer outputs.
+
+use crate::SquirrelRng;
+
+/// An integer type that [`SquirrelRng::next`] can produce.
+///
+/// Each implementation uses the rng's dedicated method for its width, so `next::<T>()` consumes
+/// the same words and returns the same value as calling that method directly.
+pub trait SquirrelWord: Sized {
+    /// Draws a value from `rng`.
+    fn from_rng(rng: &mut SquirrelRng) -> Self;
+}
+
+impl SquirrelWord for u16 {
+    /// Consumes one word; see [`SquirrelRng::next_u16`].
+    #[inline]
+    fn from_rng(rng: &mut SquirrelRng) -> Self {
+        rng.next_u16()
+    }
+}
+
+impl SquirrelWord for u32 {
+    /// Consumes one word.
+    #[inline]
+    fn from_rng(rng: &mut SquirrelRng) -> Self {
+        rng.next_u32()
+    }
+}
+
+impl SquirrelWord for u64 {
+    /// Consumes two words, low word first.
+    #[inline]
+    fn from_rng(rng: &mut SquirrelRng) -> Self {
+        rng.next_u64()
+    }
+}
+
+impl SquirrelRng {
+    /// Returns the next value of any [`SquirrelWord`] type, such as `u16`, `u32`, or `u64`.
+    // Not `Iterator::next`: the output type is chosen per call. Iterate over `&mut SquirrelRng`
+    // for a `u32` iterator instead.
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn next<T: SquirrelWord>(&mut self) -> T {
+        T::from_rng(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SquirrelRng;
+
+    #[test]
+    fn next_matches_width_specific_methods() {
+        let mut a = SquirrelRng::with_seed(3);
+        let mut b = a;
+
+        assert_eq!(a.next::<u16>(), b.next_u16());
+        assert_eq!(a.next::<u32>(), b.next_u32());
+        assert_eq!(a.next::<u64>(), b.next_u64());
+        assert_eq!(a.position(), 4);
+        assert_eq!(a, b);
+
+        let value: u64 = SquirrelRng::with_seed(3).next();
+        assert_eq!(value, SquirrelRng::with_seed(3).next::<u64>());
+    }
+}